@@ -1,7 +1,7 @@
 use std::collections::BTreeMap;
 use xo_api_client::{
     api::vm::{Vm, VmId},
-    credentials::EmailAndPassword,
+    credentials::{EmailAndPassword, Secret},
     Client,
 };
 
@@ -16,7 +16,7 @@ impl xo_api_client::api::vm::OtherInfo for OtherInfo {}
 async fn main() {
     let url = "ws://localhost:8080/api/";
     let email = String::from("admin@admin.net");
-    let password = String::from("admin");
+    let password = Secret::from("admin");
 
     let con = Client::connect(url)
         .await
@@ -0,0 +1,211 @@
+#[cfg(test)]
+mod tests;
+
+use std::collections::BTreeMap;
+
+use jsonrpsee_types::{DeserializeOwned, JsonValue};
+
+use crate::{
+    api::{
+        host::HostId,
+        message::{Message, MessageId},
+        pool::PoolId,
+        sr::SrId,
+        task::{Task, TaskId},
+        vbd::VbdId,
+        vdi::VdiId,
+        vm::{OtherInfo, Snapshot, SnapshotId, TemplateId, Vm, VmId},
+    },
+    declare_id_type,
+    types::XoObjectMap,
+    ObjectType,
+};
+
+declare_id_type! {
+    /// Unique id of an object of any type
+    ///
+    /// The ids of objects with a dedicated type can be converted into an `ObjectId`
+    pub struct ObjectId;
+}
+
+macro_rules! impl_into_object_id {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for ObjectId {
+                fn from(id: $t) -> Self {
+                    ObjectId(id.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_object_id!(
+    HostId, MessageId, PoolId, SnapshotId, SrId, TaskId, TemplateId, VbdId, VdiId, VmId
+);
+
+/// Map able to hold every object known to xo-server, regardless of type
+///
+/// Example of dumping the entire inventory in one call
+/// ```no_run
+/// # async fn example(con: xo_api_client::Client) {
+/// use xo_api_client::AnyObjectMap;
+///
+/// let all_objects: AnyObjectMap = con
+///     .xo
+///     .get_objects(None, None)
+///     .await
+///     .expect("Failed to list objects");
+/// # }
+/// ```
+pub type AnyObjectMap<O = BTreeMap<String, String>> = BTreeMap<ObjectId, AnyObject<O>>;
+
+impl<O: OtherInfo> XoObjectMap for AnyObjectMap<O> {
+    const OBJECT_TYPE: Option<ObjectType> = None;
+}
+
+/// Object of any type, as returned by `xo.getAllObjects` when no type filter is used
+///
+/// The variant is selected based on the `type` field of the object. Objects of types
+/// without a dedicated struct in this crate are kept as raw [`JsonValue`]s. Objects
+/// with a type not known by this crate, or which do not match their dedicated struct,
+/// end up in [`AnyObject::Unknown`] so that one odd object does not fail the entire map.
+///
+/// `O` is the type used for the "other" section of VMs, see [`OtherInfo`]
+#[derive(Debug)]
+pub enum AnyObject<O = BTreeMap<String, String>> {
+    GpuGroup(JsonValue),
+    Host(JsonValue),
+    HostPatch(JsonValue),
+    Message(Message),
+    Network(JsonValue),
+    Pbd(JsonValue),
+    Pci(JsonValue),
+    Pgpu(JsonValue),
+    Pif(JsonValue),
+    Pool(JsonValue),
+    PoolPatch(JsonValue),
+    Sm(JsonValue),
+    Sr(JsonValue),
+    Task(Task),
+    Vbd(JsonValue),
+    Vdi(JsonValue),
+    VdiSnapshot(JsonValue),
+    VdiUnmanaged(JsonValue),
+    Vgpu(JsonValue),
+    VgpuType(JsonValue),
+    Vif(JsonValue),
+    Vm(Vm<O>),
+    VmController(JsonValue),
+    VmSnapshot(Snapshot),
+    VmTemplate(JsonValue),
+
+    /// Object of a type not known by this crate, without a `type` field, or which failed
+    /// to deserialize into the dedicated struct of its type
+    Unknown(JsonValue),
+}
+
+impl<O> AnyObject<O> {
+    /// Get the type of the object
+    pub fn object_type(&self) -> ObjectType {
+        match self {
+            AnyObject::GpuGroup(_) => ObjectType::GpuGroup,
+            AnyObject::Host(_) => ObjectType::Host,
+            AnyObject::HostPatch(_) => ObjectType::HostPatch,
+            AnyObject::Message(_) => ObjectType::Message,
+            AnyObject::Network(_) => ObjectType::Network,
+            AnyObject::Pbd(_) => ObjectType::Pbd,
+            AnyObject::Pci(_) => ObjectType::Pci,
+            AnyObject::Pgpu(_) => ObjectType::Pgpu,
+            AnyObject::Pif(_) => ObjectType::Pif,
+            AnyObject::Pool(_) => ObjectType::Pool,
+            AnyObject::PoolPatch(_) => ObjectType::PoolPatch,
+            AnyObject::Sm(_) => ObjectType::Sm,
+            AnyObject::Sr(_) => ObjectType::Sr,
+            AnyObject::Task(_) => ObjectType::Task,
+            AnyObject::Vbd(_) => ObjectType::Vbd,
+            AnyObject::Vdi(_) => ObjectType::Vdi,
+            AnyObject::VdiSnapshot(_) => ObjectType::VdiSnapshot,
+            AnyObject::VdiUnmanaged(_) => ObjectType::VdiUnmanaged,
+            AnyObject::Vgpu(_) => ObjectType::Vgpu,
+            AnyObject::VgpuType(_) => ObjectType::VgpuType,
+            AnyObject::Vif(_) => ObjectType::Vif,
+            AnyObject::Vm(_) => ObjectType::Vm,
+            AnyObject::VmController(_) => ObjectType::VmController,
+            AnyObject::VmSnapshot(_) => ObjectType::VmSnapshot,
+            AnyObject::VmTemplate(_) => ObjectType::VmTemplate,
+            AnyObject::Unknown(value) => match value.get("type").and_then(JsonValue::as_str) {
+                Some(object_type) => match object_type.parse() {
+                    Ok(object_type) => object_type,
+                    Err(impossible) => match impossible {},
+                },
+                None => ObjectType::Unknown(String::new()),
+            },
+        }
+    }
+}
+
+impl<'de, O: OtherInfo> serde::Deserialize<'de> for AnyObject<O> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        /// Deserialize into the dedicated struct, falling back to `Unknown` on failure
+        fn typed<T: DeserializeOwned, O>(
+            value: JsonValue,
+            variant: impl FnOnce(T) -> AnyObject<O>,
+        ) -> AnyObject<O> {
+            match T::deserialize(&value) {
+                Ok(object) => variant(object),
+                Err(e) => {
+                    log::warn!(
+                        "Failed to deserialize object {:?} of type {:?}: {}",
+                        value.get("id"),
+                        value.get("type"),
+                        e
+                    );
+                    AnyObject::Unknown(value)
+                }
+            }
+        }
+
+        let value = JsonValue::deserialize(deserializer)?;
+        let object_type = match value.get("type").and_then(JsonValue::as_str) {
+            Some(object_type) => object_type.parse(),
+            None => return Ok(AnyObject::Unknown(value)),
+        };
+        let object_type = match object_type {
+            Ok(object_type) => object_type,
+            Err(impossible) => match impossible {},
+        };
+
+        Ok(match object_type {
+            ObjectType::GpuGroup => AnyObject::GpuGroup(value),
+            ObjectType::Host => AnyObject::Host(value),
+            ObjectType::HostPatch => AnyObject::HostPatch(value),
+            ObjectType::Message => typed(value, AnyObject::Message),
+            ObjectType::Network => AnyObject::Network(value),
+            ObjectType::Pbd => AnyObject::Pbd(value),
+            ObjectType::Pci => AnyObject::Pci(value),
+            ObjectType::Pgpu => AnyObject::Pgpu(value),
+            ObjectType::Pif => AnyObject::Pif(value),
+            ObjectType::Pool => AnyObject::Pool(value),
+            ObjectType::PoolPatch => AnyObject::PoolPatch(value),
+            ObjectType::Sm => AnyObject::Sm(value),
+            ObjectType::Sr => AnyObject::Sr(value),
+            ObjectType::Task => typed(value, AnyObject::Task),
+            ObjectType::Vbd => AnyObject::Vbd(value),
+            ObjectType::Vdi => AnyObject::Vdi(value),
+            ObjectType::VdiSnapshot => AnyObject::VdiSnapshot(value),
+            ObjectType::VdiUnmanaged => AnyObject::VdiUnmanaged(value),
+            ObjectType::Vgpu => AnyObject::Vgpu(value),
+            ObjectType::VgpuType => AnyObject::VgpuType(value),
+            ObjectType::Vif => AnyObject::Vif(value),
+            ObjectType::Vm => typed(value, AnyObject::Vm),
+            ObjectType::VmController => AnyObject::VmController(value),
+            ObjectType::VmSnapshot => typed(value, AnyObject::VmSnapshot),
+            ObjectType::VmTemplate => AnyObject::VmTemplate(value),
+            ObjectType::Unknown(_) => AnyObject::Unknown(value),
+        })
+    }
+}
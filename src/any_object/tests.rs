@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+
+use jsonrpsee_types::JsonValue;
+
+use super::{AnyObject, AnyObjectMap, ObjectId};
+use crate::ObjectType;
+
+#[test]
+fn mixed_objects() {
+    let vm: JsonValue =
+        serde_json::from_str(include_str!("../../test_data/vm/debian_10.json")).unwrap();
+    let snapshot: JsonValue =
+        serde_json::from_str(include_str!("../../test_data/snapshot/debian_10.json")).unwrap();
+    let host = serde_json::json!({ "type": "host", "id": "host-id" });
+    let unknown = serde_json::json!({ "type": "some-future-type", "id": "unknown-id" });
+
+    let objects = serde_json::json!({
+        "vm-id": vm,
+        "snapshot-id": snapshot,
+        "host-id": host,
+        "unknown-id": unknown,
+    });
+
+    let objects: AnyObjectMap = serde_json::from_value(objects).unwrap();
+    assert_eq!(objects.len(), 4);
+
+    let get = |id: &str| &objects[&ObjectId(id.to_string())];
+
+    match get("vm-id") {
+        AnyObject::Vm(vm) => assert_eq!(vm.name_label, "debian 10"),
+        other => panic!("Expected VM, got {:?}", other),
+    }
+    match get("snapshot-id") {
+        AnyObject::VmSnapshot(snapshot) => {
+            assert_eq!(snapshot.name_label, "[XO My Backup Job] debian 10")
+        }
+        other => panic!("Expected VM snapshot, got {:?}", other),
+    }
+    assert!(matches!(get("host-id"), AnyObject::Host(host) if host["id"] == "host-id"));
+    assert!(matches!(get("unknown-id"), AnyObject::Unknown(_)));
+    assert_eq!(
+        get("unknown-id").object_type(),
+        ObjectType::Unknown("some-future-type".to_string())
+    );
+}
+
+#[test]
+fn missing_type() {
+    let object: AnyObject<BTreeMap<String, String>> =
+        serde_json::from_value(serde_json::json!({ "id": "foo" })).unwrap();
+
+    assert!(matches!(object, AnyObject::Unknown(_)));
+}
+
+#[test]
+fn invalid_typed_object() {
+    let vm: JsonValue =
+        serde_json::from_str(include_str!("../../test_data/vm/debian_10.json")).unwrap();
+    let broken_vm = serde_json::json!({ "type": "VM", "id": "broken-id" });
+
+    let objects = serde_json::json!({
+        "vm-id": vm,
+        "broken-id": broken_vm,
+    });
+
+    let objects: AnyObjectMap = serde_json::from_value(objects).unwrap();
+    assert_eq!(objects.len(), 2);
+
+    let vm = &objects[&ObjectId("vm-id".to_string())];
+    assert!(matches!(vm, AnyObject::Vm(_)));
+
+    let broken_vm = &objects[&ObjectId("broken-id".to_string())];
+    assert!(matches!(broken_vm, AnyObject::Unknown(_)));
+    assert_eq!(broken_vm.object_type(), ObjectType::Vm);
+}
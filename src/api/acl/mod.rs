@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests;
+
+mod plan;
+mod types;
+pub use plan::{DesiredAcls, PermissionPlan};
+pub use types::{Acl, AclAction, Permission, Role, SubjectId};
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer};
+use jsonrpsee_ws_client::WsClient;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use crate::{procedure_args, ObjectId, RpcError};
+
+pub struct AclProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl AclProcedures {
+    /// Grant a role on an object to a user or group
+    ///
+    /// xo-cli: acl.add subject=<string> object=<string> action=<string>
+    pub async fn add(
+        &self,
+        subject: impl Into<SubjectId>,
+        object: impl Into<ObjectId>,
+        action: AclAction,
+    ) -> Result<(), RpcError> {
+        let params = procedure_args! {
+            "subject" => subject.into(),
+            "object" => object.into(),
+            "action" => action,
+        };
+
+        self.inner
+            .request::<bool>("acl.add", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Revoke a role on an object from a user or group
+    ///
+    /// xo-cli: acl.remove subject=<string> object=<string> action=<string>
+    pub async fn remove(
+        &self,
+        subject: impl Into<SubjectId>,
+        object: impl Into<ObjectId>,
+        action: AclAction,
+    ) -> Result<(), RpcError> {
+        let params = procedure_args! {
+            "subject" => subject.into(),
+            "object" => object.into(),
+            "action" => action,
+        };
+
+        self.inner
+            .request::<bool>("acl.remove", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get all ACLs
+    ///
+    /// xo-cli: acl.get
+    pub async fn get(&self) -> Result<Vec<Acl>, RpcError> {
+        self.inner
+            .request("acl.get", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Get the permissions granted through ACLs to the signed in user, indexed by object
+    ///
+    /// xo-cli: acl.getCurrentPermissions
+    pub async fn get_current_permissions(
+        &self,
+    ) -> Result<BTreeMap<ObjectId, BTreeSet<Permission>>, RpcError> {
+        let permissions: BTreeMap<ObjectId, BTreeMap<Permission, bool>> = self
+            .inner
+            .request(
+                "acl.getCurrentPermissions",
+                Some(ParamsSer::Map(procedure_args! {})),
+            )
+            .await?;
+
+        Ok(permissions
+            .into_iter()
+            .map(|(object, permissions)| {
+                let permissions = permissions
+                    .into_iter()
+                    .filter_map(|(permission, granted)| granted.then_some(permission))
+                    .collect();
+                (object, permissions)
+            })
+            .collect())
+    }
+}
+
+pub struct RoleProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl RoleProcedures {
+    /// Get all roles that can be granted through ACLs
+    ///
+    /// xo-cli: role.getAll
+    pub async fn get_all(&self) -> Result<Vec<Role>, RpcError> {
+        self.inner
+            .request("role.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+}
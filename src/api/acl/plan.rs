@@ -0,0 +1,111 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use crate::{
+    api::acl::{Acl, AclAction, AclProcedures, SubjectId},
+    ObjectId, RpcError,
+};
+
+/// Desired roles of users and groups, indexed by subject and then by object
+///
+/// As each role includes the permissions of the lower ones, a single role per object is
+/// enough. The mapping can be deserialized, for example from a JSON file kept in git:
+/// ```json
+/// {
+///     "<group id>": { "<vm id>": "operator", "<pool id>": "viewer" },
+///     "<user id>": {}
+/// }
+/// ```
+pub type DesiredAcls = BTreeMap<SubjectId, BTreeMap<ObjectId, AclAction>>;
+
+/// Minimal set of ACL changes bringing the current ACLs in line with [`DesiredAcls`]
+///
+/// Only the subjects present in the desired mapping are managed: any of their ACLs not
+/// in the mapping is removed, while the ACLs of other subjects are left alone. Include
+/// a subject with no objects to revoke all of its ACLs.
+///
+/// The plan can be printed through its `Display` implementation before being applied
+/// with [`AclProcedures::apply_plan`]. Applying it again afterwards is a no-op.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PermissionPlan {
+    pub add: Vec<Acl>,
+    pub remove: Vec<Acl>,
+}
+
+impl PermissionPlan {
+    /// Diff the desired ACLs against the current ones, as returned by [`AclProcedures::get`]
+    pub fn new(desired: &DesiredAcls, current: &[Acl]) -> Self {
+        let wanted: BTreeSet<Acl> = desired
+            .iter()
+            .flat_map(|(subject, objects)| {
+                objects.iter().map(move |(object, action)| Acl {
+                    subject: subject.clone(),
+                    object: object.clone(),
+                    action: *action,
+                })
+            })
+            .collect();
+        let current: BTreeSet<Acl> = current
+            .iter()
+            .filter(|acl| desired.contains_key(&acl.subject))
+            .cloned()
+            .collect();
+
+        PermissionPlan {
+            add: wanted.difference(&current).cloned().collect(),
+            remove: current.difference(&wanted).cloned().collect(),
+        }
+    }
+
+    /// Whether the current ACLs already match the desired ones
+    pub fn is_empty(&self) -> bool {
+        self.add.is_empty() && self.remove.is_empty()
+    }
+}
+
+impl fmt::Display for PermissionPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "ACLs are up to date");
+        }
+
+        let changes = self
+            .add
+            .iter()
+            .map(|acl| ('+', acl))
+            .chain(self.remove.iter().map(|acl| ('-', acl)));
+        for (sign, acl) in changes {
+            writeln!(
+                f,
+                "{} {} {:?} on {}",
+                sign, acl.subject.0, acl.action, acl.object.0
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl AclProcedures {
+    /// Carry out a [`PermissionPlan`]
+    ///
+    /// ACLs are added before old ones are removed, so that subjects changing role keep
+    /// their access throughout. Stops at the first call that fails, running the plan
+    /// again after computing it anew picks up where it stopped.
+    pub async fn apply_plan(&self, plan: &PermissionPlan) -> Result<(), RpcError> {
+        for acl in &plan.add {
+            log::debug!("apply_plan: adding {:?}", acl);
+            self.add(acl.subject.clone(), acl.object.clone(), acl.action)
+                .await?;
+        }
+        for acl in &plan.remove {
+            log::debug!("apply_plan: removing {:?}", acl);
+            self.remove(acl.subject.clone(), acl.object.clone(), acl.action)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
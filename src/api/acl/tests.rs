@@ -0,0 +1,93 @@
+use super::{Acl, AclAction, Permission, Role, SubjectId};
+use crate::{
+    api::{group::GroupId, vm::VmId},
+    ObjectId,
+};
+
+#[test]
+fn acls() {
+    let s = include_str!("../../../test_data/acl/acls.json");
+    let acls: Vec<Acl> = serde_json::from_str(s).unwrap();
+
+    assert_eq!(
+        acls[0],
+        Acl {
+            subject: GroupId("6c1d0f5e-3b2a-4e8f-9d7c-5a4b3c2d1e0f".to_string()).into(),
+            object: VmId("deadbeaf-dead-beaf-dead-beafdeadbeaf".to_string()).into(),
+            action: AclAction::Operator,
+        }
+    );
+    assert_eq!(acls[1].action, AclAction::Viewer);
+    assert_eq!(
+        acls[1].subject,
+        SubjectId("a1b2c3d4-0000-4000-8000-123456789abc".to_string())
+    );
+    assert_eq!(
+        acls[1].object,
+        ObjectId("d3f1a7e2-0b4c-8e5d-2a9f-6c1b3e7d4a58".to_string())
+    );
+}
+
+#[test]
+fn roles() {
+    let s = include_str!("../../../test_data/acl/roles.json");
+    let roles: Vec<Role> = serde_json::from_str(s).unwrap();
+
+    let ids: Vec<_> = roles.iter().map(|role| role.id).collect();
+    assert_eq!(
+        ids,
+        [AclAction::Viewer, AclAction::Operator, AclAction::Admin]
+    );
+    assert!(roles[2].permissions.contains(&Permission::Administrate));
+    assert!(!roles[1].permissions.contains(&Permission::Administrate));
+
+    // Each role includes the permissions of the previous ones
+    assert!(roles
+        .windows(2)
+        .all(|pair| pair[0].permissions.is_subset(&pair[1].permissions)));
+    assert!(AclAction::Viewer < AclAction::Admin);
+}
+
+#[test]
+fn permission_plan() {
+    use super::{DesiredAcls, PermissionPlan};
+
+    let s = include_str!("../../../test_data/acl/plan/desired.json");
+    let desired: DesiredAcls = serde_json::from_str(s).unwrap();
+    // In order: up to date, wrong role, not wanted anymore (twice) and a subject not
+    // managed by the plan
+    let s = include_str!("../../../test_data/acl/plan/current.json");
+    let current: Vec<Acl> = serde_json::from_str(s).unwrap();
+
+    let plan = PermissionPlan::new(&desired, &current);
+    assert_eq!(
+        plan.add,
+        [Acl {
+            subject: SubjectId("ops".to_string()),
+            object: ObjectId("vm-2".to_string()),
+            action: AclAction::Viewer,
+        }]
+    );
+    assert_eq!(
+        plan.remove,
+        [current[3].clone(), current[1].clone(), current[2].clone()]
+    );
+    assert_eq!(
+        plan.to_string(),
+        "+ ops Viewer on vm-2\n\
+         - leaver Admin on vm-1\n\
+         - ops Admin on vm-2\n\
+         - ops Viewer on vm-3\n"
+    );
+
+    // Once applied, there is nothing left to do
+    let applied: Vec<_> = current
+        .iter()
+        .filter(|acl| !plan.remove.contains(acl))
+        .chain(&plan.add)
+        .cloned()
+        .collect();
+    let plan = PermissionPlan::new(&desired, &applied);
+    assert!(plan.is_empty());
+    assert_eq!(plan.to_string(), "ACLs are up to date\n");
+}
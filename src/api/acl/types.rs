@@ -0,0 +1,72 @@
+use std::collections::BTreeSet;
+
+use jsonrpsee_types::JsonValue;
+
+use crate::{
+    api::{group::GroupId, user::UserId},
+    declare_id_type, ObjectId,
+};
+
+declare_id_type! {
+    /// Id of the subject of an ACL, either a user or a group
+    pub struct SubjectId;
+}
+
+impl From<UserId> for SubjectId {
+    fn from(UserId(id): UserId) -> Self {
+        SubjectId(id)
+    }
+}
+
+impl From<GroupId> for SubjectId {
+    fn from(GroupId(id): GroupId) -> Self {
+        SubjectId(id)
+    }
+}
+
+/// ACL entry, granting a role on an object to a user or group
+#[derive(serde::Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Acl {
+    pub subject: SubjectId,
+    pub object: ObjectId,
+    pub action: AclAction,
+}
+
+/// Role granted by an ACL, each role includes the permissions of the previous ones
+#[derive(
+    serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum AclAction {
+    /// Can see the object
+    Viewer,
+
+    /// Can also operate the object, like starting and stopping a VM
+    Operator,
+
+    /// Can also change and delete the object
+    Admin,
+}
+
+impl From<AclAction> for JsonValue {
+    fn from(action: AclAction) -> Self {
+        serde_json::to_value(action).unwrap()
+    }
+}
+
+/// Permission on an object, see [`Role::permissions`]
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Permission {
+    View,
+    Operate,
+    Administrate,
+}
+
+/// Role that can be granted through an ACL
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct Role {
+    pub id: AclAction,
+    pub name: String,
+    pub permissions: BTreeSet<Permission>,
+}
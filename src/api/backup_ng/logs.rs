@@ -0,0 +1,200 @@
+use std::time::Duration;
+
+use jsonrpsee_types::JsonValue;
+
+use crate::api::{
+    backup_ng::{BackupJobId, ScheduleId},
+    vm::VmId,
+};
+
+/// Log of a single run of a backup job
+///
+/// The log is a tree of tasks, the run has one task per VM which in turn has tasks for
+/// snapshotting the VM and exporting it to each target. Exports have tasks for the
+/// transfer and any merge of older backups.
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupRunLog {
+    pub job_id: Option<BackupJobId>,
+    pub job_name: Option<String>,
+    pub schedule_id: Option<ScheduleId>,
+
+    /// The root task of the run, its id is the id of the log
+    #[serde(flatten)]
+    pub task: BackupLogTask,
+}
+
+/// Task in a [`BackupRunLog`]
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct BackupLogTask {
+    #[serde(default)]
+    pub id: Option<String>,
+
+    /// What the task does, for example `backup`, `snapshot`, `export`, `transfer` or `merge`
+    #[serde(default)]
+    pub message: String,
+
+    /// Unix timestamp in milliseconds of when the task started
+    pub start: u64,
+
+    /// Unix timestamp in milliseconds of when the task ended
+    #[serde(default)]
+    pub end: Option<u64>,
+    pub status: BackupLogStatus,
+
+    /// Information about the object the task is about, see [`Self::vm_id`] and [`Self::target`]
+    #[serde(default)]
+    pub data: Option<JsonValue>,
+
+    /// Result of the task, contains the error if the task failed
+    #[serde(default)]
+    pub result: Option<JsonValue>,
+
+    #[serde(default)]
+    pub infos: Vec<BackupLogEntry>,
+
+    #[serde(default)]
+    pub warnings: Vec<BackupLogEntry>,
+
+    #[serde(default)]
+    pub tasks: Vec<BackupLogTask>,
+}
+
+/// Info or warning attached to a [`BackupLogTask`]
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct BackupLogEntry {
+    pub message: String,
+    #[serde(default)]
+    pub data: Option<JsonValue>,
+}
+
+/// Status of a [`BackupLogTask`]
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BackupLogStatus {
+    Pending,
+    Success,
+    Failure,
+    Skipped,
+    Interrupted,
+}
+
+/// Target of an export task in a backup log
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackupTarget {
+    Remote(String),
+    Sr(String),
+}
+
+/// VM that failed to be backed up, see [`BackupRunLog::failed_vms`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailedVm {
+    pub vm_id: VmId,
+
+    /// Error message of the failure, if reported
+    pub message: Option<String>,
+}
+
+impl BackupLogTask {
+    /// Time it took to run the task, `None` if it has not ended yet
+    pub fn duration(&self) -> Option<Duration> {
+        let end = self.end?;
+        Some(Duration::from_millis(end.saturating_sub(self.start)))
+    }
+
+    /// Iterator over this task and all its subtasks, recursively
+    pub fn iter(&self) -> impl Iterator<Item = &BackupLogTask> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let task = stack.pop()?;
+            stack.extend(task.tasks.iter().rev());
+            Some(task)
+        })
+    }
+
+    /// Id of the VM if this is the task for backing up a VM
+    pub fn vm_id(&self) -> Option<VmId> {
+        match self.data_type()? {
+            "VM" => Some(VmId(self.data_id()?.to_string())),
+            _ => None,
+        }
+    }
+
+    /// Target if this is the task for exporting a VM to a remote or SR
+    pub fn target(&self) -> Option<BackupTarget> {
+        let id = self.data_id()?.to_string();
+        match self.data_type()? {
+            "remote" => Some(BackupTarget::Remote(id)),
+            "SR" => Some(BackupTarget::Sr(id)),
+            _ => None,
+        }
+    }
+
+    /// Error message of the task if it failed
+    pub fn error_message(&self) -> Option<&str> {
+        if self.status != BackupLogStatus::Failure {
+            return None;
+        }
+        self.result.as_ref()?.get("message")?.as_str()
+    }
+
+    /// Number of bytes reported by the task, used by transfer and merge tasks
+    pub fn size(&self) -> Option<u64> {
+        self.result.as_ref()?.get("size")?.as_u64()
+    }
+
+    fn data_type(&self) -> Option<&str> {
+        self.data.as_ref()?.get("type")?.as_str()
+    }
+
+    fn data_id(&self) -> Option<&str> {
+        self.data.as_ref()?.get("id")?.as_str()
+    }
+}
+
+impl BackupRunLog {
+    /// Overall status of the run
+    pub fn status(&self) -> BackupLogStatus {
+        self.task.status
+    }
+
+    /// Tasks for backing up each VM
+    pub fn vm_tasks(&self) -> impl Iterator<Item = &BackupLogTask> {
+        self.task.tasks.iter().filter(|task| task.vm_id().is_some())
+    }
+
+    /// VMs that failed to be backed up, along with their error messages
+    pub fn failed_vms(&self) -> Vec<FailedVm> {
+        self.vm_tasks()
+            .filter(|task| task.status == BackupLogStatus::Failure)
+            .filter_map(|task| {
+                let message = task
+                    .iter()
+                    .find_map(BackupLogTask::error_message)
+                    .map(ToString::to_string);
+                Some(FailedVm {
+                    vm_id: task.vm_id()?,
+                    message,
+                })
+            })
+            .collect()
+    }
+
+    /// Total number of bytes successfully transferred to all targets
+    pub fn transferred_bytes(&self) -> u64 {
+        self.task
+            .iter()
+            .filter(|task| task.message == "transfer" && task.status == BackupLogStatus::Success)
+            .filter_map(BackupLogTask::size)
+            .sum()
+    }
+
+    /// Durations of all finished merges of older backups
+    pub fn merge_durations(&self) -> Vec<Duration> {
+        self.task
+            .iter()
+            .filter(|task| task.message == "merge")
+            .filter_map(BackupLogTask::duration)
+            .collect()
+    }
+}
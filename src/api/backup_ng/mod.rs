@@ -0,0 +1,306 @@
+#[cfg(test)]
+mod tests;
+
+mod logs;
+mod restore;
+mod types;
+pub use crate::api::schedule::ScheduleId;
+pub use logs::{
+    BackupLogEntry, BackupLogStatus, BackupLogTask, BackupRunLog, BackupTarget, FailedVm,
+};
+pub use restore::{
+    BackupDisk, BackupDiskId, BackupFileEntry, BackupPartition, BackupVm, VmBackup, VmBackupId,
+};
+pub use types::{
+    id_pattern, BackupJob, BackupJobId, BackupJobSettings, BackupMode, BackupSettings, Compression,
+    NewBackupJob, ReportWhen,
+};
+
+use futures::Stream;
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::{collections::BTreeMap, io, sync::Arc};
+
+use crate::{
+    api::{remote::RemoteId, sr::SrId, vm::VmId},
+    http, procedure_args, struct_to_map, RpcError,
+};
+
+pub struct BackupNgProcedures {
+    pub(crate) inner: Arc<WsClient>,
+
+    /// The URL the client connected to, used for downloads over HTTP
+    pub(crate) url: Arc<str>,
+}
+
+impl BackupNgProcedures {
+    /// Create a new backup job, returns the id of the created job
+    ///
+    /// Note that the job will not run until a schedule is created for it
+    ///
+    /// xo-cli: backupNg.createJob [compression=<unknown type>] mode=<unknown type> [name=<string>] [proxy=<string>] [remotes=<object>] [schedules=<object>] settings=<object> [srs=<object>] vms=<object>
+    pub async fn create_job(&self, job: NewBackupJob) -> Result<BackupJobId, RpcError> {
+        struct_to_map!(let params = job);
+
+        self.inner
+            .request("backupNg.createJob", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Replace the backup job with the same id as `job`
+    ///
+    /// xo-cli: backupNg.editJob [compression=<unknown type>] id=<string> [mode=<unknown type>] [name=<string>] [proxy=<string|null>] [remotes=<object>] [settings=<object>] [srs=<object>] [vms=<object>]
+    pub async fn edit_job(&self, job: BackupJob) -> Result<(), RpcError> {
+        struct_to_map!(let params = job);
+
+        self.inner
+            .request::<bool>("backupNg.editJob", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get backup job with the specified id
+    ///
+    /// xo-cli: backupNg.getJob id=<string>
+    pub async fn get_job(&self, job_id: BackupJobId) -> Result<BackupJob, RpcError> {
+        let params = procedure_args! { "id" => job_id };
+
+        self.inner
+            .request("backupNg.getJob", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get all backup jobs
+    ///
+    /// xo-cli: backupNg.getAllJobs
+    pub async fn get_all_jobs(&self) -> Result<Vec<BackupJob>, RpcError> {
+        self.inner
+            .request(
+                "backupNg.getAllJobs",
+                Some(ParamsSer::Map(procedure_args! {})),
+            )
+            .await
+    }
+
+    /// Delete backup job with the specified id
+    ///
+    /// Note that this does not delete any backups created by the job
+    ///
+    /// xo-cli: backupNg.deleteJob id=<string>
+    pub async fn delete_job(&self, job_id: BackupJobId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => job_id };
+
+        self.inner
+            .request::<bool>("backupNg.deleteJob", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Run backup job now, using the settings of the specified schedule
+    ///
+    /// `vms`: If set, only back up those VMs instead of all VMs matched by the job
+    ///
+    /// xo-cli: backupNg.runJob id=<string> schedule=<string> [settings=<object>] [vm=<string>] [vms=<array>]
+    pub async fn run_job(
+        &self,
+        job_id: BackupJobId,
+        schedule_id: ScheduleId,
+        vms: impl Into<Option<Vec<VmId>>>,
+    ) -> Result<(), RpcError> {
+        let mut params = procedure_args! { "id" => job_id, "schedule" => schedule_id };
+        if let Some(vms) = vms.into() {
+            params.insert("vms", vms.into());
+        }
+
+        self.inner
+            .request::<JsonValue>("backupNg.runJob", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get logs of backup runs
+    /// * `after` if set, only get logs of runs started after this unix timestamp in milliseconds
+    /// * `before` if set, only get logs of runs started before this unix timestamp in milliseconds
+    /// * `limit` is an optional max limit on number of results
+    ///
+    /// xo-cli: backupNg.getLogs [after=<number|string>] [before=<number|string>] [limit=<number>] *=<any>
+    pub async fn get_logs(
+        &self,
+        after: impl Into<Option<u64>>,
+        before: impl Into<Option<u64>>,
+        limit: impl Into<Option<usize>>,
+    ) -> Result<BTreeMap<String, BackupRunLog>, RpcError> {
+        let mut params = procedure_args! {};
+        if let Some(after) = after.into() {
+            params.insert("after", after.into());
+        }
+        if let Some(before) = before.into() {
+            params.insert("before", before.into());
+        }
+        if let Some(limit) = limit.into() {
+            params.insert("limit", limit.into());
+        }
+
+        self.inner
+            .request("backupNg.getLogs", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get logs of all backup runs, oldest first
+    ///
+    /// xo-cli: backupNg.getAllLogs [ndjson=<boolean>]
+    pub async fn get_all_logs(&self) -> Result<Vec<BackupRunLog>, RpcError> {
+        self.inner
+            .request(
+                "backupNg.getAllLogs",
+                Some(ParamsSer::Map(procedure_args! {})),
+            )
+            .await
+    }
+
+    /// List all VM backups stored on the specified remotes
+    ///
+    /// The result is indexed by remote and then by the id of the backed up VM
+    ///
+    /// xo-cli: backupNg.listVmBackups [_forceRefresh=<boolean>] remotes=<array>
+    pub async fn list_vm_backups(
+        &self,
+        remotes: Vec<RemoteId>,
+    ) -> Result<BTreeMap<RemoteId, BTreeMap<VmId, Vec<VmBackup>>>, RpcError> {
+        let params = procedure_args! { "remotes" => remotes };
+
+        self.inner
+            .request("backupNg.listVmBackups", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Restore a VM backup to the specified SR, returns the id of the restored VM
+    ///
+    /// xo-cli: backupNg.importVmBackup id=<string> settings=<object> sr=<string>
+    pub async fn import_vm_backup(
+        &self,
+        backup_id: VmBackupId,
+        sr_id: SrId,
+    ) -> Result<VmId, RpcError> {
+        let params = procedure_args! {
+            "id" => backup_id,
+            "settings" => serde_json::Map::new(),
+            "sr" => sr_id,
+        };
+
+        self.inner
+            .request("backupNg.importVmBackup", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Delete a VM backup from its remote
+    ///
+    /// xo-cli: backupNg.deleteVmBackup id=<string>
+    pub async fn delete_vm_backup(&self, backup_id: VmBackupId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => backup_id };
+
+        self.inner
+            .request::<JsonValue>("backupNg.deleteVmBackup", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// List partitions of a disk in a VM backup
+    ///
+    /// xo-cli: backupNg.listPartitions disk=<string> remote=<string>
+    pub async fn list_partitions(
+        &self,
+        remote_id: RemoteId,
+        disk_id: BackupDiskId,
+    ) -> Result<Vec<BackupPartition>, RpcError> {
+        let params = procedure_args! { "disk" => disk_id, "remote" => remote_id };
+
+        self.inner
+            .request("backupNg.listPartitions", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// List files in a directory of a disk in a VM backup
+    ///
+    /// `partition`: Id of the partition, should be `None` for disks without partition table
+    ///
+    /// xo-cli: backupNg.listFiles disk=<string> [partition=<string>] path=<string> remote=<string>
+    pub async fn list_files(
+        &self,
+        remote_id: RemoteId,
+        disk_id: BackupDiskId,
+        partition: impl Into<Option<String>>,
+        path: String,
+    ) -> Result<Vec<BackupFileEntry>, RpcError> {
+        let mut params = procedure_args! {
+            "disk" => disk_id,
+            "path" => path,
+            "remote" => remote_id,
+        };
+        if let Some(partition) = partition.into() {
+            params.insert("partition", partition.into());
+        }
+
+        let entries: BTreeMap<String, JsonValue> = self
+            .inner
+            .request("backupNg.listFiles", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(entries
+            .into_keys()
+            .map(BackupFileEntry::from_listing_name)
+            .collect())
+    }
+
+    /// Download files from a disk in a VM backup
+    ///
+    /// The files are packed in a zip archive which is streamed from the server.
+    ///
+    /// `partition`: Id of the partition, should be `None` for disks without partition table
+    ///
+    /// xo-cli: backupNg.fetchFiles disk=<string> [partition=<string>] paths=<array> remote=<string>
+    pub async fn fetch_files(
+        &self,
+        remote_id: RemoteId,
+        disk_id: BackupDiskId,
+        partition: impl Into<Option<String>>,
+        paths: Vec<String>,
+    ) -> Result<impl Stream<Item = io::Result<Vec<u8>>> + Send, FetchFilesError> {
+        #[derive(serde::Deserialize)]
+        struct FetchFilesResult {
+            #[serde(rename = "$getFrom")]
+            get_from: String,
+        }
+
+        let mut params = procedure_args! {
+            "disk" => disk_id,
+            "paths" => paths,
+            "remote" => remote_id,
+        };
+        if let Some(partition) = partition.into() {
+            params.insert("partition", partition.into());
+        }
+
+        let FetchFilesResult { get_from } = self
+            .inner
+            .request("backupNg.fetchFiles", Some(ParamsSer::Map(params)))
+            .await
+            .map_err(FetchFilesError::Rpc)?;
+
+        let url = http::download_url(&self.url, &get_from).ok_or(FetchFilesError::InvalidUrl)?;
+        http::get(&url).await.map_err(FetchFilesError::Http)
+    }
+}
+
+/// Error while fetching files from a VM backup
+#[derive(Debug)]
+pub enum FetchFilesError {
+    /// The URL used to connect to the server could not be turned into an HTTP URL
+    InvalidUrl,
+    Http(io::Error),
+    Rpc(RpcError),
+}
@@ -0,0 +1,97 @@
+use jsonrpsee_types::JsonValue;
+
+use crate::{
+    api::backup_ng::{BackupJobId, BackupMode, ScheduleId},
+    declare_id_type,
+};
+
+declare_id_type! {
+    /// Unique id of a VM backup stored on a remote
+    pub struct VmBackupId;
+}
+
+declare_id_type! {
+    /// Unique id of a disk in a VM backup
+    pub struct BackupDiskId;
+}
+
+/// Type representing a backup of a VM stored on a remote
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VmBackup {
+    pub id: VmBackupId,
+    pub job_id: Option<BackupJobId>,
+    pub schedule_id: Option<ScheduleId>,
+    pub mode: BackupMode,
+
+    /// Size of the backup in bytes
+    #[serde(default)]
+    pub size: Option<u64>,
+
+    /// Unix timestamp in milliseconds of when the backup was made
+    pub timestamp: u64,
+
+    /// The VM as it was at the time of the backup
+    pub vm: BackupVm,
+
+    /// Disks of the backup, these are used for file level restore
+    #[serde(default)]
+    pub disks: Vec<BackupDisk>,
+}
+
+/// Information about the VM in a [`VmBackup`]
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct BackupVm {
+    pub name_label: String,
+    #[serde(default)]
+    pub name_description: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Disk in a [`VmBackup`]
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct BackupDisk {
+    pub id: BackupDiskId,
+    pub name: String,
+    pub uuid: String,
+}
+
+/// Partition of a disk in a [`VmBackup`]
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct BackupPartition {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Size of the partition in bytes
+    #[serde(default)]
+    pub size: Option<u64>,
+
+    /// Partition type, as reported by the partition table
+    #[serde(rename = "type", default)]
+    pub partition_type: Option<JsonValue>,
+}
+
+/// Entry of a directory in a [`VmBackup`], see [`crate::api::backup_ng::BackupNgProcedures::list_files`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupFileEntry {
+    pub name: String,
+    pub is_directory: bool,
+}
+
+impl BackupFileEntry {
+    /// XO lists directories with a trailing `/`
+    pub(crate) fn from_listing_name(name: String) -> Self {
+        match name.strip_suffix('/') {
+            Some(directory) => BackupFileEntry {
+                name: directory.to_string(),
+                is_directory: true,
+            },
+            None => BackupFileEntry {
+                name,
+                is_directory: false,
+            },
+        }
+    }
+}
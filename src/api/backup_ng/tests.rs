@@ -0,0 +1,121 @@
+use super::{id_pattern, BackupJob, BackupMode, Compression, ReportWhen, ScheduleId};
+
+#[test]
+fn delta_job() {
+    let s = include_str!("../../../test_data/backup_ng/delta_job.json");
+    let job: BackupJob = serde_json::from_str(s).unwrap();
+
+    assert_eq!(job.id.0, "deadbeaf-dead-beaf-dead-beafdeadbea6");
+    assert_eq!(job.name, "My Backup Job");
+    assert_eq!(job.mode, BackupMode::Delta);
+    assert_eq!(job.compression, Compression::None);
+    assert_eq!(
+        job.vms,
+        id_pattern([
+            "deadbeaf-dead-beaf-dead-beafdeadbeaf",
+            "deadbeaf-dead-beaf-dead-beafdeadbeae"
+        ])
+    );
+    assert_eq!(
+        job.remotes,
+        Some(id_pattern(["deadbeaf-dead-beaf-dead-beafdeadbea7"]))
+    );
+
+    let global = &job.settings.global;
+    assert_eq!(global.report_when, Some(ReportWhen::Failure));
+    assert_eq!(global.concurrency, Some(2));
+    assert_eq!(global.offline_snapshot, Some(false));
+    assert_eq!(global.other["timeout"], 0);
+
+    let schedule =
+        &job.settings.schedules[&ScheduleId("deadbeaf-dead-beaf-dead-beafdeadbea8".to_string())];
+    assert_eq!(schedule.export_retention, Some(7));
+    assert_eq!(schedule.snapshot_retention, Some(1));
+    assert_eq!(schedule.copy_retention, None);
+}
+
+#[test]
+fn settings_round_trip() {
+    let s = include_str!("../../../test_data/backup_ng/delta_job.json");
+    let job: BackupJob = serde_json::from_str(s).unwrap();
+
+    let json = serde_json::to_value(&job).unwrap();
+    assert_eq!(json["settings"][""]["reportWhen"], "failure");
+    assert_eq!(json["settings"][""]["timeout"], 0);
+    assert_eq!(
+        json["settings"]["deadbeaf-dead-beaf-dead-beafdeadbea8"]["exportRetention"],
+        7
+    );
+    assert!(json["settings"][""].get("exportRetention").is_none());
+    assert!(json.get("userId").is_none());
+}
+
+#[test]
+fn run_log() {
+    use super::{BackupLogStatus, BackupRunLog, BackupTarget, FailedVm};
+    use crate::api::vm::VmId;
+    use std::time::Duration;
+
+    let s = include_str!("../../../test_data/backup_ng/run_log.json");
+    let log: BackupRunLog = serde_json::from_str(s).unwrap();
+
+    assert_eq!(log.task.id.as_deref(), Some("1623464711000"));
+    assert_eq!(log.job_name.as_deref(), Some("My Backup Job"));
+    assert_eq!(log.status(), BackupLogStatus::Failure);
+    assert_eq!(log.task.duration(), Some(Duration::from_millis(72800)));
+    assert_eq!(log.vm_tasks().count(), 2);
+
+    assert_eq!(
+        log.failed_vms(),
+        vec![FailedVm {
+            vm_id: VmId("deadbeaf-dead-beaf-dead-beafdeadbeae".to_string()),
+            message: Some("VM_BAD_POWER_STATE(OpaqueRef:deadbeaf, running, halted)".to_string()),
+        }]
+    );
+    assert_eq!(log.transferred_bytes(), 1073741824);
+    assert_eq!(log.merge_durations(), vec![Duration::from_secs(10)]);
+
+    let export = log
+        .task
+        .iter()
+        .find(|task| task.message == "export")
+        .unwrap();
+    assert_eq!(
+        export.target(),
+        Some(BackupTarget::Remote(
+            "deadbeaf-dead-beaf-dead-beafdeadbea7".to_string()
+        ))
+    );
+    assert_eq!(log.task.tasks[1].warnings.len(), 1);
+}
+
+#[test]
+fn vm_backups() {
+    use super::{BackupFileEntry, VmBackup};
+    use crate::api::{remote::RemoteId, vm::VmId};
+    use std::collections::BTreeMap;
+
+    let s = include_str!("../../../test_data/backup_ng/vm_backups.json");
+    let backups: BTreeMap<RemoteId, BTreeMap<VmId, Vec<VmBackup>>> =
+        serde_json::from_str(s).unwrap();
+
+    let remote = RemoteId("deadbeaf-dead-beaf-dead-beafdeadbea7".to_string());
+    let vm = VmId("deadbeaf-dead-beaf-dead-beafdeadbeaf".to_string());
+    let backup = &backups[&remote][&vm][0];
+
+    assert_eq!(backup.mode, BackupMode::Delta);
+    assert_eq!(backup.timestamp, 1623464711000);
+    assert_eq!(backup.size, Some(1073741824));
+    assert_eq!(backup.vm.name_label, "debian 10");
+    assert_eq!(backup.disks.len(), 1);
+    assert!(backup.disks[0].id.0.ends_with(".vhd"));
+
+    assert_eq!(
+        BackupFileEntry::from_listing_name("etc/".to_string()),
+        BackupFileEntry {
+            name: "etc".to_string(),
+            is_directory: true
+        }
+    );
+    assert!(!BackupFileEntry::from_listing_name("hosts".to_string()).is_directory);
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+
+use jsonrpsee_types::JsonValue;
+
+use crate::{api::schedule::ScheduleId, declare_id_type};
+
+declare_id_type! {
+    /// Unique id of a backup job
+    pub struct BackupJobId;
+}
+
+/// Type representing a backup job
+///
+/// `vms`, `remotes` and `srs` are XO patterns selecting the objects the job applies to,
+/// see [`id_pattern`] for the simple case of a list of ids.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupJob {
+    pub id: BackupJobId,
+    #[serde(default)]
+    pub name: String,
+    pub mode: BackupMode,
+    #[serde(default)]
+    pub compression: Compression,
+
+    /// Id of the proxy used to run the job, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+
+    /// Pattern of the VMs to back up
+    pub vms: JsonValue,
+
+    /// Pattern of the remotes to export backups to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remotes: Option<JsonValue>,
+
+    /// Pattern of the SRs to replicate VMs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub srs: Option<JsonValue>,
+    pub settings: BackupJobSettings,
+
+    /// Id of the user who owns the job
+    #[serde(default, skip_serializing)]
+    pub user_id: Option<String>,
+}
+
+/// Parameters for a new backup job, see [`BackupJob`] for details about each field
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct NewBackupJob {
+    pub name: String,
+    pub mode: BackupMode,
+    pub compression: Compression,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    pub vms: JsonValue,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remotes: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub srs: Option<JsonValue>,
+    pub settings: BackupJobSettings,
+}
+
+/// Type of backup performed by a job
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BackupMode {
+    /// Every backup is a full export of the VMs
+    Full,
+
+    /// Only the changes since the previous backup are exported, apart from the first one
+    Delta,
+
+    /// Backups are copied from one remote to others
+    Mirror,
+}
+
+/// Compression of full backups
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Compression {
+    #[default]
+    #[serde(rename = "")]
+    None,
+
+    /// Gzip
+    #[serde(rename = "native")]
+    Native,
+    #[serde(rename = "zstd")]
+    Zstd,
+}
+
+/// Settings of a backup job
+///
+/// XO stores the settings common to the whole job under the empty key, and the
+/// settings of each schedule under the id of the schedule.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Default)]
+#[serde(from = "BTreeMap<String, S>")]
+#[serde(into = "BTreeMap<String, S>")]
+#[serde(bound(
+    serialize = "S: serde::Serialize + Clone",
+    deserialize = "S: serde::Deserialize<'de> + Default"
+))]
+pub struct BackupJobSettings<S = BackupSettings> {
+    /// Settings common to all schedules of the job
+    pub global: S,
+
+    /// Settings specific to each schedule
+    pub schedules: BTreeMap<ScheduleId, S>,
+}
+
+impl<S: Default> From<BTreeMap<String, S>> for BackupJobSettings<S> {
+    fn from(mut settings: BTreeMap<String, S>) -> Self {
+        BackupJobSettings {
+            global: settings.remove("").unwrap_or_default(),
+            schedules: settings
+                .into_iter()
+                .map(|(id, settings)| (ScheduleId(id), settings))
+                .collect(),
+        }
+    }
+}
+
+impl<S> From<BackupJobSettings<S>> for BTreeMap<String, S> {
+    fn from(settings: BackupJobSettings<S>) -> Self {
+        std::iter::once((String::new(), settings.global))
+            .chain(
+                settings
+                    .schedules
+                    .into_iter()
+                    .map(|(ScheduleId(id), settings)| (id, settings)),
+            )
+            .collect()
+    }
+}
+
+/// Backup settings, either for an entire job or for a single schedule
+///
+/// Unset values use the defaults of xo-server. Settings not modelled by this type are
+/// kept in `other`.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupSettings {
+    /// When to send a report after the job has run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub report_when: Option<ReportWhen>,
+
+    /// Max number of VMs backed up at the same time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub concurrency: Option<u32>,
+
+    /// Shut down VMs before snapshotting them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offline_snapshot: Option<bool>,
+
+    /// Number of backups to keep on each remote
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub export_retention: Option<u32>,
+
+    /// Number of replicas to keep on each SR
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copy_retention: Option<u32>,
+
+    /// Number of snapshots to keep of each VM
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot_retention: Option<u32>,
+
+    #[serde(flatten)]
+    pub other: BTreeMap<String, JsonValue>,
+}
+
+/// When a report should be sent after a job has run
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportWhen {
+    Always,
+    Failure,
+    Never,
+}
+
+/// Create a pattern matching all objects with one of the specified ids
+///
+/// This is the kind of pattern used by XO for [`BackupJob::vms`], [`BackupJob::remotes`]
+/// and [`BackupJob::srs`] when objects are selected one by one.
+pub fn id_pattern<I: Into<JsonValue>>(ids: impl IntoIterator<Item = I>) -> JsonValue {
+    let mut ids: Vec<JsonValue> = ids.into_iter().map(Into::into).collect();
+
+    let id = match ids.len() {
+        1 => ids.remove(0),
+        _ => serde_json::json!({ "__or": ids }),
+    };
+
+    serde_json::json!({ "id": id })
+}
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer};
+use jsonrpsee_ws_client::WsClient;
+
+use crate::{api::user::UserId, declare_id_type, procedure_args, RpcError};
+
+declare_id_type! {
+    /// Unique id of a group of users
+    pub struct GroupId;
+}
+
+/// Type representing a group of users
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Group {
+    pub id: GroupId,
+    pub name: String,
+    #[serde(default)]
+    pub users: Vec<UserId>,
+
+    /// External authentication provider the group is synchronized from, like `ldap`
+    #[serde(default)]
+    pub provider: Option<String>,
+
+    /// Id of the group in the external authentication provider
+    #[serde(default)]
+    pub provider_group_id: Option<String>,
+}
+
+pub struct GroupProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl GroupProcedures {
+    /// Create a new group, returns the id of the created group
+    ///
+    /// xo-cli: group.create name=<string>
+    pub async fn create(&self, name: String) -> Result<GroupId, RpcError> {
+        let params = procedure_args! { "name" => name };
+
+        self.inner
+            .request("group.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get all groups
+    ///
+    /// xo-cli: group.getAll
+    pub async fn get_all(&self) -> Result<Vec<Group>, RpcError> {
+        self.inner
+            .request("group.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Rename a group
+    ///
+    /// xo-cli: group.set id=<string> [name=<string>]
+    pub async fn set(&self, group_id: GroupId, name: String) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => group_id, "name" => name };
+
+        self.inner
+            .request::<bool>("group.set", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Delete a group
+    ///
+    /// Note that this does not delete the users of the group
+    ///
+    /// xo-cli: group.delete id=<string>
+    pub async fn delete(&self, group_id: GroupId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => group_id };
+
+        self.inner
+            .request::<bool>("group.delete", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Add a user to a group
+    ///
+    /// xo-cli: group.addUser id=<string> userId=<string>
+    pub async fn add_user(&self, group_id: GroupId, user_id: UserId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => group_id, "userId" => user_id };
+
+        self.inner
+            .request::<bool>("group.addUser", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Remove a user from a group
+    ///
+    /// xo-cli: group.removeUser id=<string> userId=<string>
+    pub async fn remove_user(&self, group_id: GroupId, user_id: UserId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => group_id, "userId" => user_id };
+
+        self.inner
+            .request::<bool>("group.removeUser", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Replace the users of a group
+    ///
+    /// xo-cli: group.setUsers id=<string> userIds=<unknown type>
+    pub async fn set_users(
+        &self,
+        group_id: GroupId,
+        user_ids: Vec<UserId>,
+    ) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => group_id, "userIds" => user_ids };
+
+        self.inner
+            .request::<bool>("group.setUsers", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer};
+use jsonrpsee_ws_client::WsClient;
+
+use crate::{
+    api::stats::{Granularity, HostStats},
+    declare_id_type, procedure_args, RpcError,
+};
+
+declare_id_type! {
+    /// Unique id of a host
+    pub struct HostId;
+}
+
+pub struct HostProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl HostProcedures {
+    /// Get performance statistics of a host
+    ///
+    /// xo-cli: host.stats host=<string> [granularity=<string>]
+    pub async fn stats(
+        &self,
+        host_id: HostId,
+        granularity: Granularity,
+    ) -> Result<HostStats, RpcError> {
+        let params = procedure_args! { "host" => host_id, "granularity" => granularity };
+
+        self.inner
+            .request("host.stats", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
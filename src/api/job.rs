@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod tests;
+
+use std::sync::Arc;
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+
+use crate::{api::backup_ng::BackupJobId, declare_id_type, procedure_args, RpcError};
+
+declare_id_type! {
+    /// Unique id of a job
+    pub struct JobId;
+}
+
+impl From<BackupJobId> for JobId {
+    fn from(BackupJobId(id): BackupJobId) -> Self {
+        JobId(id)
+    }
+}
+
+/// Type representing a generic job, which calls an API method when run
+///
+/// Backup jobs are managed through [`crate::api::backup_ng::BackupNgProcedures`] instead
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Job {
+    pub id: JobId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Type of the job, `call` for jobs calling an API method
+    #[serde(rename = "type")]
+    pub job_type: String,
+
+    /// Kind of job, used by xo-web to group jobs, for example `genericTask`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+
+    /// API method called by the job, like `vm.start`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+
+    /// Description of the parameters of each call of `method`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params_vector: Option<JsonValue>,
+
+    /// Max duration of a run in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+
+    /// Id of the user who owns the job
+    #[serde(default, skip_serializing)]
+    pub user_id: Option<String>,
+}
+
+/// Parameters for a new job, see [`Job`] for details about each field
+#[derive(serde::Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NewJob {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub job_type: String,
+    pub key: String,
+    pub method: String,
+    pub params_vector: JsonValue,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+}
+
+pub struct JobProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl JobProcedures {
+    /// Create a new job, returns the id of the created job
+    ///
+    /// Note that the job will not run until a schedule is created for it
+    ///
+    /// xo-cli: job.create job=<object>
+    pub async fn create(&self, job: NewJob) -> Result<JobId, RpcError> {
+        let params = procedure_args! { "job" => serde_json::to_value(job)? };
+
+        self.inner
+            .request("job.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get job with the specified id
+    ///
+    /// xo-cli: job.get id=<string>
+    pub async fn get(&self, job_id: JobId) -> Result<Job, RpcError> {
+        let params = procedure_args! { "id" => job_id };
+
+        self.inner
+            .request("job.get", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get all jobs
+    ///
+    /// xo-cli: job.getAll
+    pub async fn get_all(&self) -> Result<Vec<Job>, RpcError> {
+        self.inner
+            .request("job.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Replace the job with the same id as `job`
+    ///
+    /// xo-cli: job.set job=<object>
+    pub async fn set(&self, job: Job) -> Result<(), RpcError> {
+        let params = procedure_args! { "job" => serde_json::to_value(job)? };
+
+        self.inner
+            .request::<JsonValue>("job.set", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Delete job with the specified id
+    ///
+    /// xo-cli: job.delete id=<string>
+    pub async fn delete(&self, job_id: JobId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => job_id };
+
+        self.inner
+            .request::<JsonValue>("job.delete", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Run jobs one after the other, in the specified order
+    ///
+    /// xo-cli: job.runSequence idSequence=<array>
+    pub async fn run_sequence(&self, job_ids: Vec<JobId>) -> Result<(), RpcError> {
+        let params = procedure_args! { "idSequence" => job_ids };
+
+        self.inner
+            .request::<JsonValue>("job.runSequence", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Cancel a current run of a job
+    ///
+    /// `run_id`: Id of the run, which is the id of its log
+    ///
+    /// xo-cli: job.cancel runId=<string>
+    pub async fn cancel(&self, run_id: String) -> Result<(), RpcError> {
+        let params = procedure_args! { "runId" => run_id };
+
+        self.inner
+            .request::<JsonValue>("job.cancel", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use super::{Job, JobId};
+
+#[test]
+fn jobs() {
+    let s = include_str!("../../../test_data/job/jobs.json");
+    let jobs: Vec<Job> = serde_json::from_str(s).unwrap();
+
+    let restart = &jobs[0];
+    assert_eq!(
+        restart.id,
+        JobId("b4e2c6f0-1f2a-4f6e-8a8a-5d9c0a7d3e21".to_string())
+    );
+    assert_eq!(restart.name.as_deref(), Some("Restart web servers"));
+    assert_eq!(restart.job_type, "call");
+    assert_eq!(restart.key.as_deref(), Some("genericTask"));
+    assert_eq!(restart.method.as_deref(), Some("vm.restart"));
+    assert_eq!(restart.timeout, Some(600000));
+    assert_eq!(
+        restart.params_vector.as_ref().unwrap()["items"][0]["values"]
+            .as_array()
+            .unwrap()
+            .len(),
+        2
+    );
+
+    // Jobs created through the API may lack a name, key and timeout
+    let start = &jobs[1];
+    assert!(start.name.is_none());
+    assert!(start.key.is_none());
+    assert!(start.timeout.is_none());
+
+    // The owner is set by xo-server and not sent back by `job.set`
+    let value = serde_json::to_value(restart).unwrap();
+    assert!(value.get("userId").is_none());
+    assert_eq!(value["paramsVector"]["type"], "crossProduct");
+}
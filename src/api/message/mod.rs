@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests;
+
+mod types;
+pub use types::{Message, MessageId};
+
+use futures::Stream;
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer};
+use jsonrpsee_ws_client::WsClient;
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::{api::xo::XoProcedures, procedure_args, RpcError};
+
+pub struct MessageProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl MessageProcedures {
+    /// Delete a message
+    ///
+    /// xo-cli: message.delete id=<string>
+    pub async fn delete(&self, message_id: MessageId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => message_id };
+
+        self.inner
+            .request::<bool>("message.delete", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Stream of new messages
+    ///
+    /// Only messages created after the stream is first polled are produced, oldest first.
+    /// * `max_priority` if set, only messages with a priority of `max_priority` or more
+    ///   important are produced, see [`Message::has_priority`]
+    /// * `poll_interval` is how often the server is polled for new messages
+    ///
+    /// Errors are reported through the stream, polling continues after an error.
+    pub fn watch(
+        &self,
+        max_priority: impl Into<Option<u8>>,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<Message, RpcError>> {
+        struct State {
+            xo: XoProcedures,
+            polled: bool,
+            seen: Option<BTreeSet<MessageId>>,
+            pending: VecDeque<Message>,
+        }
+
+        let max_priority = max_priority.into();
+        let state = State {
+            xo: XoProcedures {
+                inner: Arc::clone(&self.inner),
+            },
+            polled: false,
+            seen: None,
+            pending: VecDeque::new(),
+        };
+
+        futures::stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(message) = state.pending.pop_front() {
+                    return Some((Ok(message), state));
+                }
+
+                // Sleep before every poll but the first, also after errors so that a
+                // failing server is not polled in a tight loop
+                if state.polled {
+                    tokio::time::sleep(poll_interval).await;
+                }
+                state.polled = true;
+
+                let messages: BTreeMap<MessageId, Message> =
+                    match state.xo.get_objects(None, None).await {
+                        Ok(messages) => messages,
+                        Err(e) => return Some((Err(e), state)),
+                    };
+
+                if let Some(seen) = &state.seen {
+                    let mut new_messages: Vec<_> = messages
+                        .values()
+                        .filter(|message| !seen.contains(&message.id))
+                        .filter(|message| max_priority.map_or(true, |p| message.has_priority(p)))
+                        .cloned()
+                        .collect();
+                    new_messages.sort_by_key(|message| message.timestamp);
+
+                    state.pending.extend(new_messages);
+                }
+
+                state.seen = Some(messages.into_keys().collect());
+            }
+        })
+    }
+}
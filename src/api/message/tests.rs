@@ -0,0 +1,27 @@
+use super::Message;
+
+#[test]
+fn alarm() {
+    let s = include_str!("../../../test_data/message/alarm.json");
+    let message: Message = serde_json::from_str(s).unwrap();
+
+    assert_eq!(message.id.0, "deadbeaf-dead-beaf-dead-beafdeadbea4");
+    assert_eq!(message.name, "ALARM");
+    assert!(message.body.contains("mem_usage"));
+    assert_eq!(message.priority, Some(3));
+    assert_eq!(message.timestamp, 1623464711);
+    assert_eq!(message.object, "deadbeaf-dead-beaf-dead-beafdeadbeaf");
+
+    assert!(message.has_priority(3));
+    assert!(!message.has_priority(2));
+}
+
+#[test]
+fn without_priority() {
+    let s = include_str!("../../../test_data/message/vm_started.json");
+    let message: Message = serde_json::from_str(s).unwrap();
+
+    assert_eq!(message.name, "VM_STARTED");
+    assert_eq!(message.priority, None);
+    assert!(message.has_priority(1));
+}
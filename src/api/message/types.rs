@@ -0,0 +1,43 @@
+use crate::{declare_id_type, impl_xo_object};
+
+declare_id_type! {
+    /// Unique id of a message
+    pub struct MessageId;
+}
+
+/// Type representing a XAPI message, this is how XAPI reports alarms and other events
+///
+/// Also see https://github.com/vatesfr/xen-orchestra/blob/a505cd9567233aab7ca6488b2fb8a0b6c610fa08/packages/xo-server/src/xapi-object-to-xo.mjs
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct Message {
+    pub id: MessageId,
+
+    /// Name of the message, for example `ALARM` or `VM_STARTED`
+    pub name: String,
+    pub body: String,
+
+    /// Priority of the message where 1 is the most important and 5 is informational
+    ///
+    /// Note that not all versions of xo-server report this
+    #[serde(default)]
+    pub priority: Option<u8>,
+
+    /// Unix timestamp of when the message was created
+    #[serde(rename = "time")]
+    pub timestamp: u64,
+
+    /// Uuid of the object the message is about
+    #[serde(rename = "$object")]
+    pub object: String,
+}
+impl_xo_object!(Message => Message, MessageId);
+
+impl Message {
+    /// Check if the message is at least as important as `max_priority`
+    ///
+    /// Messages without any known priority are always considered important enough
+    pub fn has_priority(&self, max_priority: u8) -> bool {
+        self.priority
+            .map_or(true, |priority| priority <= max_priority)
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod tests;
+
+mod types;
+pub use types::{
+    MetadataBackup, MetadataBackupId, MetadataBackupJob, MetadataBackupJobId,
+    MetadataBackupSettings, MetadataBackups, NewMetadataBackupJob, NewMetadataBackupSchedule,
+};
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::{
+    api::{remote::RemoteId, schedule::ScheduleId},
+    procedure_args, struct_to_map, RpcError,
+};
+
+pub struct MetadataBackupProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl MetadataBackupProcedures {
+    /// Create a new metadata backup job along with its schedules, returns the id of the
+    /// created job
+    ///
+    /// xo-cli: metadataBackup.createJob [name=<string>] [pools=<object>] [proxy=<string>] remotes=<object> schedules=<object> settings=<object> [xoMetadata=<boolean>]
+    pub async fn create_job(
+        &self,
+        job: NewMetadataBackupJob,
+    ) -> Result<MetadataBackupJobId, RpcError> {
+        struct_to_map!(let params = job);
+
+        self.inner
+            .request("metadataBackup.createJob", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Replace the metadata backup job with the same id as `job`
+    ///
+    /// xo-cli: metadataBackup.editJob id=<string> [name=<string>] [pools=<object|null>] [proxy=<string|null>] [settings=<object>] [remotes=<object>] [xoMetadata=<boolean>]
+    pub async fn edit_job(&self, job: MetadataBackupJob) -> Result<(), RpcError> {
+        struct_to_map!(let params = job);
+        let mut params = params;
+        // Explicitly unset the pools if the job no longer backs up pool metadata
+        params.entry("pools").or_insert(JsonValue::Null);
+
+        self.inner
+            .request::<JsonValue>("metadataBackup.editJob", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get metadata backup job with the specified id
+    ///
+    /// xo-cli: metadataBackup.getJob id=<string>
+    pub async fn get_job(
+        &self,
+        job_id: MetadataBackupJobId,
+    ) -> Result<MetadataBackupJob, RpcError> {
+        let params = procedure_args! { "id" => job_id };
+
+        self.inner
+            .request("metadataBackup.getJob", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get all metadata backup jobs
+    ///
+    /// xo-cli: metadataBackup.getAllJobs
+    pub async fn get_all_jobs(&self) -> Result<Vec<MetadataBackupJob>, RpcError> {
+        self.inner
+            .request(
+                "metadataBackup.getAllJobs",
+                Some(ParamsSer::Map(procedure_args! {})),
+            )
+            .await
+    }
+
+    /// Delete metadata backup job with the specified id, along with its schedules
+    ///
+    /// Note that this does not delete any backups created by the job
+    ///
+    /// xo-cli: metadataBackup.deleteJob id=<string>
+    pub async fn delete_job(&self, job_id: MetadataBackupJobId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => job_id };
+
+        self.inner
+            .request::<JsonValue>("metadataBackup.deleteJob", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Run metadata backup job now, using the settings of the specified schedule
+    ///
+    /// xo-cli: metadataBackup.runJob id=<string> schedule=<string>
+    pub async fn run_job(
+        &self,
+        job_id: MetadataBackupJobId,
+        schedule_id: ScheduleId,
+    ) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => job_id, "schedule" => schedule_id };
+
+        self.inner
+            .request::<JsonValue>("metadataBackup.runJob", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// List all metadata backups stored on the specified remotes
+    ///
+    /// xo-cli: metadataBackup.list remotes=<array>
+    pub async fn list(&self, remotes: Vec<RemoteId>) -> Result<MetadataBackups, RpcError> {
+        let params = procedure_args! { "remotes" => remotes };
+
+        self.inner
+            .request("metadataBackup.list", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Restore a metadata backup
+    ///
+    /// Restoring pool metadata replaces the metadata of the pool, restoring an XO config
+    /// replaces the configuration of the XO instance the client is connected to.
+    ///
+    /// xo-cli: metadataBackup.restore id=<string>
+    pub async fn restore(&self, backup_id: MetadataBackupId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => backup_id };
+
+        self.inner
+            .request::<JsonValue>("metadataBackup.restore", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Delete a metadata backup from its remote
+    ///
+    /// xo-cli: metadataBackup.delete id=<string>
+    pub async fn delete(&self, backup_id: MetadataBackupId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => backup_id };
+
+        self.inner
+            .request::<JsonValue>("metadataBackup.delete", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use super::{MetadataBackupJob, MetadataBackups};
+use crate::api::{pool::PoolId, remote::RemoteId, schedule::ScheduleId};
+
+#[test]
+fn job() {
+    let s = include_str!("../../../test_data/metadata_backup/job.json");
+    let job: MetadataBackupJob = serde_json::from_str(s).unwrap();
+
+    assert!(job.xo_metadata);
+    assert!(job.pools.is_some());
+    assert_eq!(job.settings.global.retention_pool_metadata, None);
+
+    let schedule =
+        &job.settings.schedules[&ScheduleId("3fa1c1d2-4a77-4c1e-9d3b-0c5a9e8a1f10".to_string())];
+    assert_eq!(schedule.retention_pool_metadata, Some(7));
+    assert_eq!(schedule.retention_xo_metadata, Some(14));
+
+    // Settings keep the empty key for the global settings when sent back
+    let value = serde_json::to_value(&job).unwrap();
+    assert_eq!(value["settings"][""]["reportWhen"], "failure");
+    assert_eq!(
+        value["settings"]["3fa1c1d2-4a77-4c1e-9d3b-0c5a9e8a1f10"]["retentionXoMetadata"],
+        14
+    );
+    assert!(value.get("userId").is_none());
+}
+
+#[test]
+fn list() {
+    let s = include_str!("../../../test_data/metadata_backup/list.json");
+    let backups: MetadataBackups = serde_json::from_str(s).unwrap();
+
+    let remote = RemoteId("8f2d9c41-6b3e-4a7d-b5c0-1e9f8a2d7c63".to_string());
+    let xo = &backups.xo[&remote];
+    assert_eq!(xo.len(), 1);
+    assert_eq!(xo[0].timestamp, 1609642800000);
+    assert!(xo[0].pool.is_none());
+
+    let pool = PoolId("d3f1a7e2-0b4c-8e5d-2a9f-6c1b3e7d4a58".to_string());
+    let pool_backups = &backups.pool[&remote][&pool];
+    assert_eq!(pool_backups[0].job_name.as_deref(), Some("DR metadata"));
+    assert!(pool_backups[0].pool_master.is_some());
+}
@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+
+use jsonrpsee_types::JsonValue;
+
+use crate::{
+    api::{
+        backup_ng::{BackupJobSettings, ReportWhen},
+        pool::PoolId,
+        remote::RemoteId,
+        schedule::{Cron, ScheduleId},
+    },
+    declare_id_type,
+};
+
+declare_id_type! {
+    /// Unique id of a metadata backup job
+    pub struct MetadataBackupJobId;
+}
+
+declare_id_type! {
+    /// Unique id of a metadata backup stored on a remote
+    pub struct MetadataBackupId;
+}
+
+/// Type representing a metadata backup job
+///
+/// A job backs up the metadata of the pools matched by `pools`, the configuration of
+/// XO itself if `xo_metadata` is set, or both. `pools` and `remotes` are XO patterns,
+/// see [`crate::api::backup_ng::id_pattern`].
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataBackupJob {
+    pub id: MetadataBackupJobId,
+    #[serde(default)]
+    pub name: String,
+
+    /// Pattern of the pools to back up the metadata of
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pools: Option<JsonValue>,
+
+    /// Whether the configuration of XO is backed up
+    #[serde(default)]
+    pub xo_metadata: bool,
+
+    /// Pattern of the remotes to store backups on
+    pub remotes: JsonValue,
+
+    /// Id of the proxy used to run the job, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    pub settings: BackupJobSettings<MetadataBackupSettings>,
+
+    /// Id of the user who owns the job
+    #[serde(default, skip_serializing)]
+    pub user_id: Option<String>,
+}
+
+/// Parameters for a new metadata backup job, see [`MetadataBackupJob`] for details
+/// about each field
+///
+/// Schedules are created along with the job, each needs an entry in
+/// `settings.schedules` with the id used as key in `schedules`.
+#[derive(serde::Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NewMetadataBackupJob {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pools: Option<JsonValue>,
+    pub xo_metadata: bool,
+    pub remotes: JsonValue,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+
+    /// Schedules to create, indexed by a temporary id also used in `settings`
+    pub schedules: BTreeMap<ScheduleId, NewMetadataBackupSchedule>,
+    pub settings: BackupJobSettings<MetadataBackupSettings>,
+}
+
+/// Schedule created along with a [`NewMetadataBackupJob`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct NewMetadataBackupSchedule {
+    pub cron: Cron,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub enabled: bool,
+}
+
+/// Metadata backup settings, either for an entire job or for a single schedule
+///
+/// The retentions are the number of backups kept on each remote. Settings not
+/// modelled by this type are kept in `other`.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataBackupSettings {
+    /// When to send a report after the job has run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub report_when: Option<ReportWhen>,
+
+    /// Number of pool metadata backups to keep on each remote
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retention_pool_metadata: Option<u32>,
+
+    /// Number of XO config backups to keep on each remote
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retention_xo_metadata: Option<u32>,
+
+    #[serde(flatten)]
+    pub other: BTreeMap<String, JsonValue>,
+}
+
+/// Metadata backups stored on remotes, see
+/// [`crate::api::metadata_backup::MetadataBackupProcedures::list`]
+#[derive(serde::Deserialize, Debug, Clone, Default)]
+pub struct MetadataBackups {
+    /// Backups of the XO config, indexed by remote
+    #[serde(default)]
+    pub xo: BTreeMap<RemoteId, Vec<MetadataBackup>>,
+
+    /// Backups of pool metadata, indexed by remote and then by pool
+    #[serde(default)]
+    pub pool: BTreeMap<RemoteId, BTreeMap<PoolId, Vec<MetadataBackup>>>,
+}
+
+/// A single metadata backup stored on a remote
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataBackup {
+    pub id: MetadataBackupId,
+    pub job_id: Option<MetadataBackupJobId>,
+    pub job_name: Option<String>,
+    pub schedule_id: Option<ScheduleId>,
+
+    /// Unix timestamp in milliseconds of when the backup was made
+    pub timestamp: u64,
+
+    /// The backed up pool, only for pool metadata backups
+    #[serde(default)]
+    pub pool: Option<JsonValue>,
+
+    /// The master host of the backed up pool, only for pool metadata backups
+    #[serde(default)]
+    pub pool_master: Option<JsonValue>,
+}
@@ -1,9 +1,31 @@
+pub mod acl;
+pub mod backup_ng;
+pub mod group;
+pub mod host;
+pub mod job;
+pub mod message;
+pub mod metadata_backup;
+pub mod pool;
+pub mod remote;
+pub mod resource_set;
+pub mod schedule;
+pub mod server;
 pub mod session;
+pub mod sr;
+pub mod stats;
+pub mod system;
+pub mod task;
 pub mod token;
+pub mod user;
+pub mod vbd;
+pub mod vdi;
 pub mod vm;
 pub mod xo;
 
-use std::sync::Arc;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
 
 use jsonrpsee_types::{traits::SubscriptionClient, DeserializeOwned, Subscription};
 use jsonrpsee_ws_client::{WsClient, WsClientBuilder};
@@ -11,7 +33,26 @@ use jsonrpsee_ws_client::{WsClient, WsClientBuilder};
 use crate::RpcError;
 
 use self::{
-    session::SessionProcedures, token::TokenProcedures, vm::VmProcedures, xo::XoProcedures,
+    acl::{AclProcedures, RoleProcedures},
+    backup_ng::BackupNgProcedures,
+    group::GroupProcedures,
+    host::HostProcedures,
+    job::JobProcedures,
+    message::MessageProcedures,
+    metadata_backup::MetadataBackupProcedures,
+    remote::RemoteProcedures,
+    resource_set::ResourceSetProcedures,
+    schedule::ScheduleProcedures,
+    server::ServerProcedures,
+    session::SessionProcedures,
+    sr::SrProcedures,
+    system::{ServerVersion, SystemProcedures, TESTED_SERVER_VERSIONS},
+    task::TaskProcedures,
+    token::TokenProcedures,
+    user::UserProcedures,
+    vdi::VdiProcedures,
+    vm::VmProcedures,
+    xo::XoProcedures,
 };
 
 /// Client used to communicate with Xen Orchestra's API
@@ -19,7 +60,7 @@ use self::{
 /// Example of listing all VMs with the tag `Test`
 /// ```no_run
 /// use std::collections::BTreeMap;
-/// use xo_api_client::{credentials::EmailAndPassword, Client, api::vm::{Vm, VmId}};
+/// use xo_api_client::{credentials::{EmailAndPassword, Secret}, Client, api::vm::{Vm, VmId}};
 ///
 /// // We dont care about any of the data under the "other" attribute
 /// // in this example
@@ -32,7 +73,7 @@ use self::{
 /// async fn main() {
 ///     let url = "ws://localhost:8080/api/";
 ///     let email = String::from("admin@admin.net");
-///     let password = String::from("admin");
+///     let password = Secret::from("admin");
 ///
 ///     let con = Client::connect(url)
 ///         .await
@@ -59,11 +100,29 @@ use self::{
 /// ```
 pub struct Client {
     inner: Arc<WsClient>,
+    authenticated: Arc<AtomicBool>,
 
     pub vm: VmProcedures,
     pub xo: XoProcedures,
     pub token: TokenProcedures,
     pub session: SessionProcedures,
+    pub task: TaskProcedures,
+    pub message: MessageProcedures,
+    pub host: HostProcedures,
+    pub sr: SrProcedures,
+    pub backup_ng: BackupNgProcedures,
+    pub metadata_backup: MetadataBackupProcedures,
+    pub remote: RemoteProcedures,
+    pub schedule: ScheduleProcedures,
+    pub job: JobProcedures,
+    pub user: UserProcedures,
+    pub group: GroupProcedures,
+    pub acl: AclProcedures,
+    pub role: RoleProcedures,
+    pub resource_set: ResourceSetProcedures,
+    pub vdi: VdiProcedures,
+    pub server: ServerProcedures,
+    pub system: SystemProcedures,
 }
 
 impl Client {
@@ -83,8 +142,11 @@ impl Client {
 
         log::debug!("Connected");
 
+        let authenticated = Arc::new(AtomicBool::new(false));
+
         Ok(Client {
             inner: Arc::clone(&inner),
+            authenticated: Arc::clone(&authenticated),
             vm: VmProcedures {
                 inner: Arc::clone(&inner),
             },
@@ -96,10 +158,105 @@ impl Client {
             },
             session: SessionProcedures {
                 inner: Arc::clone(&inner),
+                authenticated,
+            },
+            task: TaskProcedures {
+                inner: Arc::clone(&inner),
+            },
+            message: MessageProcedures {
+                inner: Arc::clone(&inner),
+            },
+            host: HostProcedures {
+                inner: Arc::clone(&inner),
+            },
+            sr: SrProcedures {
+                inner: Arc::clone(&inner),
+            },
+            backup_ng: BackupNgProcedures {
+                inner: Arc::clone(&inner),
+                url: Arc::from(url),
+            },
+            metadata_backup: MetadataBackupProcedures {
+                inner: Arc::clone(&inner),
+            },
+            remote: RemoteProcedures {
+                inner: Arc::clone(&inner),
+            },
+            schedule: ScheduleProcedures {
+                inner: Arc::clone(&inner),
+            },
+            job: JobProcedures {
+                inner: Arc::clone(&inner),
+            },
+            user: UserProcedures {
+                inner: Arc::clone(&inner),
+            },
+            group: GroupProcedures {
+                inner: Arc::clone(&inner),
+            },
+            acl: AclProcedures {
+                inner: Arc::clone(&inner),
+            },
+            role: RoleProcedures {
+                inner: Arc::clone(&inner),
+            },
+            resource_set: ResourceSetProcedures {
+                inner: Arc::clone(&inner),
+            },
+            vdi: VdiProcedures {
+                inner: Arc::clone(&inner),
+            },
+            server: ServerProcedures {
+                inner: Arc::clone(&inner),
+            },
+            system: SystemProcedures {
+                inner: Arc::clone(&inner),
             },
         })
     }
 
+    /// Connect to xo-server, like [`Self::connect`], and check its version
+    ///
+    /// A warning is logged if the version of xo-server is not in
+    /// [`TESTED_SERVER_VERSIONS`], or could not be determined. Connecting still succeeds
+    /// in that case.
+    pub async fn connect_with_version_check(url: &str) -> Result<Self, RpcError> {
+        let client = Self::connect(url).await?;
+
+        let version = match client.system.get_server_version().await {
+            Ok(version) => version,
+            Err(e) => {
+                log::warn!("Failed to get the server version: {}", e);
+                return Ok(client);
+            }
+        };
+        match version.parse::<ServerVersion>() {
+            Ok(parsed) if parsed.is_tested() => {
+                log::debug!("Server version: {}", parsed);
+            }
+            Ok(parsed) => log::warn!(
+                "Server version {} is outside of the versions targeted by this crate ({} to {})",
+                parsed,
+                TESTED_SERVER_VERSIONS.start(),
+                TESTED_SERVER_VERSIONS.end()
+            ),
+            Err(e) => log::warn!("{}", e),
+        }
+
+        Ok(client)
+    }
+
+    /// Whether the client has successfully signed in, and not signed out since
+    ///
+    /// This only tracks calls made through [`SessionProcedures`]. It is not cleared if
+    /// the websocket connection drops, or if xo-server ends the session on its own, in
+    /// which case calls fail even though this returns `true`.
+    ///
+    /// See [`SessionProcedures::sign_in`]
+    pub fn is_authenticated(&self) -> bool {
+        self.authenticated.load(Ordering::SeqCst)
+    }
+
     /// Subscribe to method "all"
     ///
     /// xo-server tends to send notifications to the client's JSON RPC procedure "all"
@@ -0,0 +1,6 @@
+use crate::declare_id_type;
+
+declare_id_type! {
+    /// Unique id of a pool
+    pub struct PoolId;
+}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests;
+
+mod types;
+mod url;
+pub use types::{Remote, RemoteChanges, RemoteId, RemoteInfo, RemoteTestResult};
+pub use url::{RemoteUrl, RemoteUrlParseError};
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer};
+use jsonrpsee_ws_client::WsClient;
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::{procedure_args, struct_to_map, RpcError};
+
+pub struct RemoteProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl RemoteProcedures {
+    /// Create a new remote
+    ///
+    /// `options`: Extra mount options, if any
+    ///
+    /// xo-cli: remote.create name=<string> [options=<string>] [proxy=<string>] url=<string>
+    pub async fn create(
+        &self,
+        name: String,
+        url: RemoteUrl,
+        options: impl Into<Option<String>>,
+    ) -> Result<Remote, RpcError> {
+        let mut params = procedure_args! { "name" => name, "url" => url.to_string() };
+        if let Some(options) = options.into() {
+            params.insert("options", options.into());
+        }
+
+        self.inner
+            .request("remote.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get remote with the specified id
+    ///
+    /// xo-cli: remote.get id=<string>
+    pub async fn get(&self, remote_id: RemoteId) -> Result<Remote, RpcError> {
+        let params = procedure_args! { "id" => remote_id };
+
+        self.inner
+            .request("remote.get", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get all remotes
+    ///
+    /// xo-cli: remote.getAll
+    pub async fn get_all(&self) -> Result<Vec<Remote>, RpcError> {
+        self.inner
+            .request("remote.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Get disk space of all remotes, indexed by remote id
+    ///
+    /// Remotes that could not be accessed are missing from the result
+    ///
+    /// xo-cli: remote.getAllInfo
+    pub async fn get_all_info(&self) -> Result<BTreeMap<RemoteId, RemoteInfo>, RpcError> {
+        self.inner
+            .request(
+                "remote.getAllInfo",
+                Some(ParamsSer::Map(procedure_args! {})),
+            )
+            .await
+    }
+
+    /// Change settings of a remote
+    ///
+    /// xo-cli: remote.set [enabled=<boolean>] id=<string> [name=<string>] [options=<string|null>] [proxy=<string|null>] [url=<string>]
+    pub async fn set(&self, remote_id: RemoteId, changes: RemoteChanges) -> Result<(), RpcError> {
+        struct_to_map!(let params = changes);
+        let mut params = params;
+        params.insert("id", remote_id.into());
+
+        self.inner
+            .request::<bool>("remote.set", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Test that the remote is accessible, and measure its read and write speed
+    ///
+    /// xo-cli: remote.test id=<string>
+    pub async fn test(&self, remote_id: RemoteId) -> Result<RemoteTestResult, RpcError> {
+        let params = procedure_args! { "id" => remote_id };
+
+        self.inner
+            .request("remote.test", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Delete a remote
+    ///
+    /// Note that this does not delete any backups stored on the remote
+    ///
+    /// xo-cli: remote.delete id=<string>
+    pub async fn delete(&self, remote_id: RemoteId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => remote_id };
+
+        self.inner
+            .request::<bool>("remote.delete", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+}
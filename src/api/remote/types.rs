@@ -0,0 +1,119 @@
+use crate::{api::remote::RemoteUrl, declare_id_type};
+
+declare_id_type! {
+    /// Unique id of a backup remote
+    pub struct RemoteId;
+}
+
+/// Type representing a backup remote, a place where backups are stored
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct Remote {
+    pub id: RemoteId,
+    pub name: String,
+    pub url: RemoteUrl,
+    pub enabled: bool,
+
+    /// Extra mount options
+    #[serde(default)]
+    pub options: Option<String>,
+
+    /// Id of the proxy used to access the remote, if any
+    #[serde(default)]
+    pub proxy: Option<String>,
+
+    /// Error from the last time the remote was accessed
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Changes to apply to a remote, unset fields are left unchanged
+#[derive(serde::Serialize, Debug, Clone, Default)]
+pub struct RemoteChanges {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<RemoteUrl>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<String>,
+
+    /// Id of the proxy used to access the remote, `Some(None)` stops using a proxy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<Option<String>>,
+}
+
+/// Disk space of a remote
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemoteInfo {
+    /// Total size in bytes
+    pub size: u64,
+
+    /// Used space in bytes
+    pub used: u64,
+}
+
+impl RemoteInfo {
+    /// Available space in bytes
+    pub fn available(&self) -> u64 {
+        self.size.saturating_sub(self.used)
+    }
+}
+
+/// Result of testing a remote
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "RawRemoteTestResult")]
+pub enum RemoteTestResult {
+    Success {
+        /// Write speed in bytes per second
+        write_rate: Option<f64>,
+
+        /// Read speed in bytes per second
+        read_rate: Option<f64>,
+    },
+    Failure {
+        /// The step of the test that failed, like `write` or `read`
+        step: Option<String>,
+
+        /// The file that was accessed when the test failed
+        file: Option<String>,
+        error: Option<String>,
+    },
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawRemoteTestResult {
+    success: bool,
+    write_rate: Option<f64>,
+    read_rate: Option<f64>,
+    step: Option<String>,
+    file: Option<String>,
+    error: Option<jsonrpsee_types::JsonValue>,
+}
+
+impl From<RawRemoteTestResult> for RemoteTestResult {
+    fn from(raw: RawRemoteTestResult) -> Self {
+        if raw.success {
+            return RemoteTestResult::Success {
+                write_rate: raw.write_rate,
+                read_rate: raw.read_rate,
+            };
+        }
+
+        // The error is usually an object with a message, but may also be a plain string
+        let error = raw.error.map(|error| match error {
+            jsonrpsee_types::JsonValue::String(message) => message,
+            error => match error.get("message").and_then(|message| message.as_str()) {
+                Some(message) => message.to_string(),
+                None => error.to_string(),
+            },
+        });
+
+        RemoteTestResult::Failure {
+            step: raw.step,
+            file: raw.file,
+            error,
+        }
+    }
+}
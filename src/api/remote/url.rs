@@ -0,0 +1,268 @@
+use std::{fmt, str::FromStr};
+
+use crate::credentials::Secret;
+
+/// Location of a backup remote
+///
+/// This is parsed from, and formatted to, the URL format used by XO. Passwords are
+/// [`Secret`]s, so they are redacted in `Debug` output, but `Display` includes them as
+/// that is the format XO expects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteUrl {
+    /// Directory on the host running xo-server
+    File { path: String },
+
+    Nfs {
+        host: String,
+        port: Option<u16>,
+        path: String,
+    },
+
+    Smb {
+        username: String,
+        password: Secret,
+        domain: String,
+
+        /// Host and share, like `server\share`
+        host: String,
+
+        /// Path inside the share
+        path: String,
+    },
+
+    /// Amazon S3 or compatible object storage
+    S3 {
+        /// `false` for plain HTTP
+        https: bool,
+
+        /// Access key id
+        username: String,
+
+        /// Secret access key
+        password: Secret,
+        host: String,
+
+        /// Bucket and optional directory, like `/bucket/dir`
+        path: String,
+        region: Option<String>,
+    },
+}
+
+/// Error returned when parsing an invalid [`RemoteUrl`]
+///
+/// Any credentials in the URL are replaced by `***`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteUrlParseError(String);
+
+impl RemoteUrlParseError {
+    fn new(url: &str) -> Self {
+        let redacted = match (url.split_once("://"), url.rsplit_once('@')) {
+            (Some((scheme, _)), Some((_, location))) => format!("{}://***@{}", scheme, location),
+            _ => url.to_string(),
+        };
+        RemoteUrlParseError(redacted)
+    }
+}
+
+impl fmt::Display for RemoteUrlParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid remote URL: {}", self.0)
+    }
+}
+
+impl std::error::Error for RemoteUrlParseError {}
+
+fn with_leading_slash(path: &str) -> String {
+    format!("/{}", path.trim_start_matches('/'))
+}
+
+impl FromStr for RemoteUrl {
+    type Err = RemoteUrlParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || RemoteUrlParseError::new(s);
+        let (scheme, rest) = s.split_once("://").ok_or_else(err)?;
+
+        match scheme {
+            "file" => Ok(RemoteUrl::File {
+                path: with_leading_slash(rest),
+            }),
+            "nfs" => {
+                // host:[port:]path
+                let (host, rest) = rest.split_once(':').ok_or_else(err)?;
+                let (port, path) = match rest.split_once(':') {
+                    Some((port, path))
+                        if !port.is_empty() && port.bytes().all(|b| b.is_ascii_digit()) =>
+                    {
+                        (Some(port.parse().map_err(|_| err())?), path)
+                    }
+                    _ => (None, rest),
+                };
+                if host.is_empty() || path.is_empty() {
+                    return Err(err());
+                }
+
+                Ok(RemoteUrl::Nfs {
+                    host: host.to_string(),
+                    port,
+                    path: with_leading_slash(path),
+                })
+            }
+            "smb" => {
+                // username:password@domain\\host[\0path]
+                let (credentials, location) = rest.rsplit_once('@').ok_or_else(err)?;
+                let (username, password) = credentials.split_once(':').ok_or_else(err)?;
+                let (domain, location) = location.split_once("\\\\").ok_or_else(err)?;
+                let (host, path) = location.split_once('\0').unwrap_or((location, ""));
+                if host.is_empty() {
+                    return Err(err());
+                }
+
+                Ok(RemoteUrl::Smb {
+                    username: username.to_string(),
+                    password: password.into(),
+                    domain: domain.to_string(),
+                    host: host.to_string(),
+                    path: path.to_string(),
+                })
+            }
+            "s3" | "s3+http" => {
+                // username:password@host/path[#region]
+                let (rest, region) = match rest.split_once('#') {
+                    Some((rest, region)) if !region.is_empty() => (rest, Some(region.to_string())),
+                    Some((rest, _)) => (rest, None),
+                    None => (rest, None),
+                };
+                let (credentials, location) = rest.rsplit_once('@').ok_or_else(err)?;
+                let (username, password) = credentials.split_once(':').ok_or_else(err)?;
+                let (host, path) = match location.find('/') {
+                    Some(i) => location.split_at(i),
+                    None => (location, "/"),
+                };
+                if host.is_empty() {
+                    return Err(err());
+                }
+
+                Ok(RemoteUrl::S3 {
+                    https: scheme == "s3",
+                    username: username.to_string(),
+                    password: percent_decode(password).ok_or_else(err)?.into(),
+                    host: host.to_string(),
+                    path: path.to_string(),
+                    region,
+                })
+            }
+            _ => Err(err()),
+        }
+    }
+}
+
+impl fmt::Display for RemoteUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RemoteUrl::File { path } => write!(f, "file://{}", path),
+            RemoteUrl::Nfs { host, port, path } => {
+                write!(f, "nfs://{}:", host)?;
+                if let Some(port) = port {
+                    write!(f, "{}:", port)?;
+                }
+                f.write_str(path)
+            }
+            RemoteUrl::Smb {
+                username,
+                password,
+                domain,
+                host,
+                path,
+            } => {
+                write!(
+                    f,
+                    "smb://{}:{}@{}\\\\{}",
+                    username,
+                    password.expose(),
+                    domain,
+                    host
+                )?;
+                if !path.is_empty() {
+                    write!(f, "\0{}", path)?;
+                }
+                Ok(())
+            }
+            RemoteUrl::S3 {
+                https,
+                username,
+                password,
+                host,
+                path,
+                region,
+            } => {
+                let scheme = if *https { "s3" } else { "s3+http" };
+                write!(
+                    f,
+                    "{}://{}:{}@{}{}",
+                    scheme,
+                    username,
+                    percent_encode(password.expose()),
+                    host,
+                    path
+                )?;
+                if let Some(region) = region {
+                    write!(f, "#{}", region)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl serde::Serialize for RemoteUrl {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for RemoteUrl {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Same as javascript's `encodeURIComponent`, which is what XO uses for S3 secrets
+fn percent_encode(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'!'
+            | b'~'
+            | b'*'
+            | b'\''
+            | b'('
+            | b')' => encoded.push(b as char),
+            b => encoded.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    encoded
+}
+
+fn percent_decode(s: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut iter = s.bytes();
+    while let Some(b) = iter.next() {
+        match b {
+            b'%' => {
+                let hex = [iter.next()?, iter.next()?];
+                let hex = std::str::from_utf8(&hex).ok()?;
+                bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            }
+            b => bytes.push(b),
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
@@ -0,0 +1,238 @@
+#[cfg(test)]
+mod tests;
+
+mod types;
+pub use types::{
+    ResourceLimit, ResourceLimitKind, ResourceSet, ResourceSetChanges, ResourceSetId,
+    ResourceSetLimits,
+};
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::{api::acl::SubjectId, procedure_args, struct_to_map, ObjectId, RpcError};
+
+pub struct ResourceSetProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl ResourceSetProcedures {
+    /// Create a new resource set
+    ///
+    /// `limits`: Total of each limit, unset limits mean unlimited
+    ///
+    /// xo-cli: resourceSet.create name=<string> [subjects=<array>] [objects=<array>] [limits=<object>]
+    pub async fn create(
+        &self,
+        name: String,
+        subjects: Vec<SubjectId>,
+        objects: Vec<ObjectId>,
+        limits: BTreeMap<ResourceLimitKind, u64>,
+    ) -> Result<ResourceSet, RpcError> {
+        let params = procedure_args! {
+            "name" => name,
+            "subjects" => subjects,
+            "objects" => objects,
+            "limits" => serde_json::to_value(limits)?,
+        };
+
+        self.inner
+            .request("resourceSet.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get resource set with the specified id
+    ///
+    /// xo-cli: resourceSet.get id=<string>
+    pub async fn get(&self, resource_set_id: ResourceSetId) -> Result<ResourceSet, RpcError> {
+        let params = procedure_args! { "id" => resource_set_id };
+
+        self.inner
+            .request("resourceSet.get", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get all resource sets
+    ///
+    /// Users who are not admins only get the resource sets they are a subject of
+    ///
+    /// xo-cli: resourceSet.getAll
+    pub async fn get_all(&self) -> Result<Vec<ResourceSet>, RpcError> {
+        self.inner
+            .request(
+                "resourceSet.getAll",
+                Some(ParamsSer::Map(procedure_args! {})),
+            )
+            .await
+    }
+
+    /// Change settings of a resource set
+    ///
+    /// xo-cli: resourceSet.set id=<string> [name=<string>] [subjects=<array>] [objects=<array>] [ipPools=<array>] [limits=<object>]
+    pub async fn set(
+        &self,
+        resource_set_id: ResourceSetId,
+        changes: ResourceSetChanges,
+    ) -> Result<(), RpcError> {
+        struct_to_map!(let params = changes);
+        let mut params = params;
+        params.insert("id", resource_set_id.into());
+
+        self.inner
+            .request::<JsonValue>("resourceSet.set", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Delete a resource set
+    ///
+    /// Note that this does not delete the VMs created through the resource set
+    ///
+    /// xo-cli: resourceSet.delete id=<string>
+    pub async fn delete(&self, resource_set_id: ResourceSetId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => resource_set_id };
+
+        self.inner
+            .request::<JsonValue>("resourceSet.delete", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Make an SR, network or template available through a resource set
+    ///
+    /// xo-cli: resourceSet.addObject id=<string> object=<string>
+    pub async fn add_object(
+        &self,
+        resource_set_id: ResourceSetId,
+        object: impl Into<ObjectId>,
+    ) -> Result<(), RpcError> {
+        self.call(
+            "resourceSet.addObject",
+            resource_set_id,
+            "object",
+            object.into(),
+        )
+        .await
+    }
+
+    /// Remove an object from a resource set
+    ///
+    /// xo-cli: resourceSet.removeObject id=<string> object=<string>
+    pub async fn remove_object(
+        &self,
+        resource_set_id: ResourceSetId,
+        object: impl Into<ObjectId>,
+    ) -> Result<(), RpcError> {
+        self.call(
+            "resourceSet.removeObject",
+            resource_set_id,
+            "object",
+            object.into(),
+        )
+        .await
+    }
+
+    /// Allow a user or group to use a resource set
+    ///
+    /// xo-cli: resourceSet.addSubject id=<string> subject=<string>
+    pub async fn add_subject(
+        &self,
+        resource_set_id: ResourceSetId,
+        subject: impl Into<SubjectId>,
+    ) -> Result<(), RpcError> {
+        self.call(
+            "resourceSet.addSubject",
+            resource_set_id,
+            "subject",
+            subject.into(),
+        )
+        .await
+    }
+
+    /// Stop allowing a user or group to use a resource set
+    ///
+    /// xo-cli: resourceSet.removeSubject id=<string> subject=<string>
+    pub async fn remove_subject(
+        &self,
+        resource_set_id: ResourceSetId,
+        subject: impl Into<SubjectId>,
+    ) -> Result<(), RpcError> {
+        self.call(
+            "resourceSet.removeSubject",
+            resource_set_id,
+            "subject",
+            subject.into(),
+        )
+        .await
+    }
+
+    /// Set the total of a limit of a resource set
+    ///
+    /// `quantity`: Number of CPUs or IP addresses, or bytes of memory or disk space
+    ///
+    /// xo-cli: resourceSet.addLimit id=<string> limitId=<string> quantity=<integer>
+    pub async fn add_limit(
+        &self,
+        resource_set_id: ResourceSetId,
+        limit: ResourceLimitKind,
+        quantity: u64,
+    ) -> Result<(), RpcError> {
+        let params = procedure_args! {
+            "id" => resource_set_id,
+            "limitId" => limit,
+            "quantity" => quantity,
+        };
+
+        self.inner
+            .request::<JsonValue>("resourceSet.addLimit", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Remove a limit of a resource set, making it unlimited
+    ///
+    /// xo-cli: resourceSet.removeLimit id=<string> limitId=<string>
+    pub async fn remove_limit(
+        &self,
+        resource_set_id: ResourceSetId,
+        limit: ResourceLimitKind,
+    ) -> Result<(), RpcError> {
+        self.call("resourceSet.removeLimit", resource_set_id, "limitId", limit)
+            .await
+    }
+
+    /// Recompute the available amounts of the limits of all resource sets from the VMs
+    /// using them
+    ///
+    /// xo-cli: resourceSet.recomputeAllLimits
+    pub async fn recompute_all_limits(&self) -> Result<(), RpcError> {
+        self.inner
+            .request::<JsonValue>(
+                "resourceSet.recomputeAllLimits",
+                Some(ParamsSer::Map(procedure_args! {})),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn call(
+        &self,
+        method: &str,
+        resource_set_id: ResourceSetId,
+        key: &'static str,
+        value: impl Into<JsonValue>,
+    ) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => resource_set_id, key => value.into() };
+
+        self.inner
+            .request::<JsonValue>(method, Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use super::{ResourceLimitKind, ResourceSet, ResourceSetChanges};
+
+#[test]
+fn resource_set() {
+    let s = include_str!("../../../test_data/resource_set/resource_set.json");
+    let resource_set: ResourceSet = serde_json::from_str(s).unwrap();
+
+    assert_eq!(resource_set.name, "Developers");
+    assert_eq!(resource_set.objects.len(), 2);
+
+    let cpus = resource_set.limits.cpus.unwrap();
+    assert_eq!(cpus.total, 16);
+    assert_eq!(cpus.used(), 10);
+
+    let memory = resource_set.limits.memory.unwrap();
+    assert_eq!(memory.used(), 51539607552);
+
+    // Over the limit after it has been lowered
+    let disk = resource_set.limits.disk.unwrap();
+    assert!(disk.available < 0);
+    assert!(disk.used() > disk.total);
+
+    let ip_pool = resource_set.limits.ip_pool("pool-1").unwrap();
+    assert_eq!(ip_pool.used(), 3);
+    assert!(resource_set.limits.ip_pool("pool-2").is_none());
+
+    let unlimited: ResourceSet =
+        serde_json::from_str(r#"{ "id": "a", "name": "Unlimited", "limits": {} }"#).unwrap();
+    assert_eq!(unlimited.limits, Default::default());
+}
+
+#[test]
+fn resource_set_changes() {
+    let changes = ResourceSetChanges {
+        limits: Some(
+            [
+                (ResourceLimitKind::Cpus, 8),
+                (ResourceLimitKind::Memory, 34359738368),
+                (ResourceLimitKind::IpPool("pool-1".to_string()), 10),
+            ]
+            .into_iter()
+            .collect(),
+        ),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(changes).unwrap(),
+        serde_json::json!({
+            "limits": { "cpus": 8, "memory": 34359738368u64, "ipPool:pool-1": 10 }
+        })
+    );
+}
@@ -0,0 +1,130 @@
+use std::{collections::BTreeMap, fmt};
+
+use jsonrpsee_types::JsonValue;
+
+use crate::{api::acl::SubjectId, declare_id_type, ObjectId};
+
+declare_id_type! {
+    /// Unique id of a resource set
+    pub struct ResourceSetId;
+}
+
+/// Type representing a resource set
+///
+/// A resource set lets its subjects create VMs on its objects (SRs, networks and
+/// templates) within its limits, without being admins.
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceSet {
+    pub id: ResourceSetId,
+    pub name: String,
+
+    /// Users and groups allowed to use the resource set
+    #[serde(default)]
+    pub subjects: Vec<SubjectId>,
+
+    /// SRs, networks and templates available through the resource set
+    #[serde(default)]
+    pub objects: Vec<ObjectId>,
+
+    /// Ids of the IP pools available through the resource set
+    #[serde(default)]
+    pub ip_pools: Vec<String>,
+    #[serde(default)]
+    pub limits: ResourceSetLimits,
+}
+
+/// Limits of a resource set, unset limits mean unlimited
+#[derive(serde::Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ResourceSetLimits {
+    /// Number of virtual CPUs
+    pub cpus: Option<ResourceLimit>,
+
+    /// Memory in bytes
+    pub memory: Option<ResourceLimit>,
+
+    /// Disk space in bytes
+    pub disk: Option<ResourceLimit>,
+
+    /// Other limits, like limits of IP pools keyed by `ipPool:<id>`
+    #[serde(flatten)]
+    pub other: BTreeMap<String, ResourceLimit>,
+}
+
+impl ResourceSetLimits {
+    /// Limit of the number of addresses used from an IP pool
+    pub fn ip_pool(&self, ip_pool_id: &str) -> Option<&ResourceLimit> {
+        self.other
+            .get(&ResourceLimitKind::IpPool(ip_pool_id.to_string()).to_string())
+    }
+}
+
+/// A single limit of a resource set
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceLimit {
+    pub total: u64,
+
+    /// May be negative if the limit has been lowered below the current usage
+    pub available: i64,
+}
+
+impl ResourceLimit {
+    /// Amount currently used by the VMs of the resource set
+    pub fn used(&self) -> u64 {
+        (i128::from(self.total) - i128::from(self.available))
+            .try_into()
+            .unwrap_or(0)
+    }
+}
+
+/// Kind of limit of a resource set
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ResourceLimitKind {
+    Cpus,
+    Memory,
+    Disk,
+
+    /// Number of addresses used from the IP pool with the given id
+    IpPool(String),
+}
+
+impl fmt::Display for ResourceLimitKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResourceLimitKind::Cpus => f.write_str("cpus"),
+            ResourceLimitKind::Memory => f.write_str("memory"),
+            ResourceLimitKind::Disk => f.write_str("disk"),
+            ResourceLimitKind::IpPool(id) => write!(f, "ipPool:{}", id),
+        }
+    }
+}
+
+impl serde::Serialize for ResourceLimitKind {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl From<ResourceLimitKind> for JsonValue {
+    fn from(kind: ResourceLimitKind) -> Self {
+        kind.to_string().into()
+    }
+}
+
+/// Changes to apply to a resource set, unset fields are left unchanged
+///
+/// `limits` replaces all the limits of the resource set, with the total of each
+#[derive(serde::Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceSetChanges {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subjects: Option<Vec<SubjectId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub objects: Option<Vec<ObjectId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_pools: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limits: Option<BTreeMap<ResourceLimitKind, u64>>,
+}
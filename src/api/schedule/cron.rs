@@ -0,0 +1,295 @@
+use std::{
+    fmt,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::date::civil_from_days;
+
+/// Parsed cron expression, as used by XO schedules
+///
+/// Both the standard 5 field format (`minute hour day-of-month month day-of-week`) and the
+/// 6 field format with leading seconds are supported. Fields may contain `*`, values,
+/// ranges (`1-5`), steps (`*/15`, `0-30/10`) and lists (`1,15`). Months and days of week
+/// may also be written as names (`jan`, `mon`), Sunday is both 0 and 7.
+///
+/// As with most cron implementations, when both day of month and day of week are
+/// restricted a day matches if either of them matches.
+///
+/// # Timezones
+///
+/// Named timezones like the [`Schedule::timezone`](super::Schedule::timezone) of a
+/// schedule are **not** resolved. [`Self::next_runs`] evaluates the expression at a
+/// fixed UTC offset supplied by the caller, so computed times are off by the DST shift
+/// for runs on the other side of a daylight saving time change than `after`. xo-server
+/// itself does follow the timezone, so treat the computed times as an approximation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cron {
+    source: String,
+    seconds: u64,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+/// Error returned when parsing an invalid cron expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronParseError(String);
+
+impl fmt::Display for CronParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid cron expression: {}", self.0)
+    }
+}
+
+impl std::error::Error for CronParseError {}
+
+const MONTHS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const DAYS_OF_WEEK: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// Give up searching for the next run after this many days, some expressions like
+/// `0 0 30 2 *` never match
+const MAX_SEARCH_DAYS: i64 = 366 * 28;
+
+struct Field {
+    min: u32,
+    max: u32,
+    names: &'static [&'static str],
+    names_offset: u32,
+}
+
+impl Field {
+    fn parse_value(&self, s: &str) -> Option<u32> {
+        let value = match s.parse() {
+            Ok(value) => value,
+            Err(_) => {
+                let lower = s.to_ascii_lowercase();
+                self.names.iter().position(|name| *name == lower)? as u32 + self.names_offset
+            }
+        };
+        (self.min..=self.max).contains(&value).then_some(value)
+    }
+
+    /// Parse a field into a bit mask of allowed values, along with whether it is `*`
+    fn parse(&self, s: &str) -> Option<(u64, bool)> {
+        let mut mask = 0;
+        for part in s.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => (range, step.parse().ok().filter(|step| *step > 0)?),
+                None => (part, 1),
+            };
+            let (start, end) = match range {
+                "*" => (self.min, self.max),
+                range => match range.split_once('-') {
+                    Some((start, end)) => (self.parse_value(start)?, self.parse_value(end)?),
+                    // `5/15` means starting at 5 until the end
+                    None if step > 1 => (self.parse_value(range)?, self.max),
+                    None => {
+                        let value = self.parse_value(range)?;
+                        (value, value)
+                    }
+                },
+            };
+            if start > end {
+                return None;
+            }
+
+            for value in (start..=end).step_by(step as usize) {
+                mask |= 1 << value;
+            }
+        }
+
+        Some((mask, s == "*"))
+    }
+}
+
+const SECONDS: Field = Field {
+    min: 0,
+    max: 59,
+    names: &[],
+    names_offset: 0,
+};
+const MINUTES: Field = SECONDS;
+const HOURS: Field = Field {
+    min: 0,
+    max: 23,
+    names: &[],
+    names_offset: 0,
+};
+const DAYS_OF_MONTH: Field = Field {
+    min: 1,
+    max: 31,
+    names: &[],
+    names_offset: 0,
+};
+const MONTHS_FIELD: Field = Field {
+    min: 1,
+    max: 12,
+    names: &MONTHS,
+    names_offset: 1,
+};
+const DAYS_OF_WEEK_FIELD: Field = Field {
+    min: 0,
+    max: 7,
+    names: &DAYS_OF_WEEK,
+    names_offset: 0,
+};
+
+impl FromStr for Cron {
+    type Err = CronParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || CronParseError(s.to_string());
+        let fields: Vec<&str> = s.split_whitespace().collect();
+        let (seconds, rest) = match fields.len() {
+            5 => ("0", &fields[..]),
+            6 => (fields[0], &fields[1..]),
+            _ => return Err(err()),
+        };
+
+        let (seconds, _) = SECONDS.parse(seconds).ok_or_else(err)?;
+        let (minutes, _) = MINUTES.parse(rest[0]).ok_or_else(err)?;
+        let (hours, _) = HOURS.parse(rest[1]).ok_or_else(err)?;
+        let (days_of_month, dom_any) = DAYS_OF_MONTH.parse(rest[2]).ok_or_else(err)?;
+        let (months, _) = MONTHS_FIELD.parse(rest[3]).ok_or_else(err)?;
+        let (mut days_of_week, dow_any) = DAYS_OF_WEEK_FIELD.parse(rest[4]).ok_or_else(err)?;
+
+        // Sunday may be written as 7
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+
+        Ok(Cron {
+            source: s.to_string(),
+            seconds,
+            minutes,
+            hours,
+            days_of_month,
+            months,
+            days_of_week,
+            day_of_month_restricted: !dom_any,
+            day_of_week_restricted: !dow_any,
+        })
+    }
+}
+
+impl fmt::Display for Cron {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl serde::Serialize for Cron {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Cron {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl Cron {
+    /// Compute the next `count` times the expression matches, strictly after `after`
+    ///
+    /// `utc_offset` is the offset in seconds of the timezone the expression is evaluated
+    /// in, for example `3600` for UTC+1. The offset is used for all runs, changes to
+    /// daylight saving time are **not** taken into account, see [the type level
+    /// documentation](Self#timezones).
+    ///
+    /// Fewer than `count` times are returned if the expression stops matching, which
+    /// can happen for dates like the 30th of February.
+    pub fn next_runs(&self, after: SystemTime, utc_offset: i32, count: usize) -> Vec<SystemTime> {
+        let mut runs = Vec::with_capacity(count);
+        let mut after = after;
+        while runs.len() < count {
+            match self.next_run(after, utc_offset) {
+                Some(run) => {
+                    runs.push(run);
+                    after = run;
+                }
+                None => break,
+            }
+        }
+        runs
+    }
+
+    /// Compute the next time the expression matches, strictly after `after`
+    ///
+    /// See [`Self::next_runs`]
+    pub fn next_run(&self, after: SystemTime, utc_offset: i32) -> Option<SystemTime> {
+        let after = match after.duration_since(UNIX_EPOCH) {
+            Ok(after) => after.as_secs() as i64,
+            Err(e) => -(e.duration().as_secs() as i64) - 1,
+        };
+        let local = after + i64::from(utc_offset) + 1;
+
+        let first_day = local.div_euclid(86400);
+        let mut start_of_day = Some(local.rem_euclid(86400) as u32);
+
+        for day in first_day..first_day + MAX_SEARCH_DAYS {
+            let earliest = start_of_day.take().unwrap_or(0);
+            if !self.matches_day(day) {
+                continue;
+            }
+
+            if let Some(time) = self.first_time_of_day(earliest) {
+                let timestamp = day * 86400 + i64::from(time) - i64::from(utc_offset);
+                return u64::try_from(timestamp)
+                    .ok()
+                    .map(|timestamp| UNIX_EPOCH + Duration::from_secs(timestamp));
+            }
+        }
+
+        None
+    }
+
+    fn matches_day(&self, days_since_epoch: i64) -> bool {
+        let (_year, month, day) = civil_from_days(days_since_epoch);
+        let weekday = (days_since_epoch + 4).rem_euclid(7) as u32;
+
+        if self.months & (1 << month) == 0 {
+            return false;
+        }
+
+        let dom = self.days_of_month & (1 << day) != 0;
+        let dow = self.days_of_week & (1 << weekday) != 0;
+        match (self.day_of_month_restricted, self.day_of_week_restricted) {
+            (true, true) => dom || dow,
+            _ => dom && dow,
+        }
+    }
+
+    /// First matching second of the day at or after `earliest` seconds into the day
+    fn first_time_of_day(&self, earliest: u32) -> Option<u32> {
+        let matches = |mask: u64, value: u32| mask & (1 << value) != 0;
+
+        for hour in earliest / 3600..24 {
+            if !matches(self.hours, hour) {
+                continue;
+            }
+            for minute in 0..60 {
+                if !matches(self.minutes, minute) {
+                    continue;
+                }
+                for second in 0..60 {
+                    let time = hour * 3600 + minute * 60 + second;
+                    if time >= earliest && matches(self.seconds, second) {
+                        return Some(time);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests;
+
+mod cron;
+mod types;
+pub use cron::{Cron, CronParseError};
+pub use types::{Schedule, ScheduleChanges, ScheduleId};
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::{api::job::JobId, procedure_args, struct_to_map, RpcError};
+
+pub struct ScheduleProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl ScheduleProcedures {
+    /// Create a new schedule running the specified job
+    ///
+    /// `timezone`: Name of the timezone `cron` is evaluated in, like `Europe/Paris`.
+    /// The timezone of xo-server is used if unset
+    ///
+    /// xo-cli: schedule.create cron=<string> [enabled=<boolean>] jobId=<string> [name=<string>] [timezone=<string>]
+    pub async fn create(
+        &self,
+        job_id: JobId,
+        cron: Cron,
+        timezone: impl Into<Option<String>>,
+        name: impl Into<Option<String>>,
+        enabled: bool,
+    ) -> Result<Schedule, RpcError> {
+        let mut params = procedure_args! {
+            "cron" => cron.to_string(),
+            "enabled" => enabled,
+            "jobId" => job_id,
+        };
+        if let Some(timezone) = timezone.into() {
+            params.insert("timezone", timezone.into());
+        }
+        if let Some(name) = name.into() {
+            params.insert("name", name.into());
+        }
+
+        self.inner
+            .request("schedule.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get schedule with the specified id
+    ///
+    /// xo-cli: schedule.get id=<string>
+    pub async fn get(&self, schedule_id: ScheduleId) -> Result<Schedule, RpcError> {
+        let params = procedure_args! { "id" => schedule_id };
+
+        self.inner
+            .request("schedule.get", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get all schedules
+    ///
+    /// xo-cli: schedule.getAll
+    pub async fn get_all(&self) -> Result<Vec<Schedule>, RpcError> {
+        self.inner
+            .request("schedule.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Change settings of a schedule
+    ///
+    /// xo-cli: schedule.set [cron=<string>] [enabled=<boolean>] id=<string> [jobId=<string>] [name=<string|null>] [timezone=<string>]
+    pub async fn set(
+        &self,
+        schedule_id: ScheduleId,
+        changes: ScheduleChanges,
+    ) -> Result<(), RpcError> {
+        struct_to_map!(let params = changes);
+        let mut params = params;
+        params.insert("id", schedule_id.into());
+
+        self.inner
+            .request::<bool>("schedule.set", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Delete a schedule
+    ///
+    /// Note that this does not delete the job run by the schedule
+    ///
+    /// xo-cli: schedule.delete id=<string>
+    pub async fn delete(&self, schedule_id: ScheduleId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => schedule_id };
+
+        self.inner
+            .request::<bool>("schedule.delete", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+}
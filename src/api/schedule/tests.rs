@@ -0,0 +1,110 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::{Cron, Schedule};
+
+/// 2021-01-01T00:00:00Z, a Friday
+const NEW_YEAR_2021: u64 = 1609459200;
+
+fn next_runs(cron: &str, utc_offset: i32, count: usize) -> Vec<u64> {
+    let cron: Cron = cron.parse().unwrap();
+    cron.next_runs(
+        UNIX_EPOCH + Duration::from_secs(NEW_YEAR_2021),
+        utc_offset,
+        count,
+    )
+    .into_iter()
+    .map(|time| time.duration_since(UNIX_EPOCH).unwrap().as_secs())
+    .collect()
+}
+
+#[test]
+fn parse_cron() {
+    for valid in [
+        "* * * * *",
+        "0 3 * * sun",
+        "*/15 0-6,18-23 1,15 JAN-jun 1-5",
+        "5/10 * * * 7",
+        "30 0 3 * * *",
+    ] {
+        let cron: Cron = valid.parse().unwrap();
+        assert_eq!(cron.to_string(), valid);
+    }
+
+    for invalid in [
+        "",
+        "* * * *",
+        "* * * * * * *",
+        "60 * * * *",
+        "* 24 * * *",
+        "* * 0 * *",
+        "* * * 13 *",
+        "* * * * 8",
+        "*/0 * * * *",
+        "10-5 * * * *",
+        "* * * foo *",
+    ] {
+        assert!(
+            invalid.parse::<Cron>().is_err(),
+            "{:?} should be invalid",
+            invalid
+        );
+    }
+}
+
+#[test]
+fn cron_next_runs() {
+    let week = 7 * 86400;
+    let first_sunday = NEW_YEAR_2021 + 2 * 86400 + 3 * 3600;
+    assert_eq!(
+        next_runs("0 3 * * sun", 0, 3),
+        [first_sunday, first_sunday + week, first_sunday + 2 * week]
+    );
+    assert_eq!(next_runs("0 3 * * 7", 0, 1), [first_sunday]);
+
+    assert_eq!(
+        next_runs("*/15 * * * *", 0, 2),
+        [NEW_YEAR_2021 + 900, NEW_YEAR_2021 + 1800]
+    );
+    assert_eq!(next_runs("30 * * * * *", 0, 1), [NEW_YEAR_2021 + 30]);
+
+    // 03:00 in UTC+1 is 02:00 UTC
+    assert_eq!(next_runs("0 3 * * *", 3600, 1), [NEW_YEAR_2021 + 2 * 3600]);
+
+    // Day of month and day of week are combined with OR when both are restricted
+    assert_eq!(
+        next_runs("0 0 13 * fri", 0, 3),
+        [
+            NEW_YEAR_2021 + 7 * 86400,
+            NEW_YEAR_2021 + 12 * 86400,
+            NEW_YEAR_2021 + 14 * 86400
+        ]
+    );
+
+    assert_eq!(next_runs("0 0 29 2 *", 0, 1), [1709164800]);
+    assert!(next_runs("0 0 30 2 *", 0, 1).is_empty());
+}
+
+#[test]
+fn cron_next_run_is_strictly_after() {
+    let cron: Cron = "0 0 * * *".parse().unwrap();
+    let midnight = UNIX_EPOCH + Duration::from_secs(NEW_YEAR_2021);
+    assert_eq!(
+        cron.next_run(midnight, 0),
+        Some(midnight + Duration::from_secs(86400))
+    );
+    assert!(cron.next_run(SystemTime::now(), 0).unwrap() > SystemTime::now());
+}
+
+#[test]
+fn schedule() {
+    let s = include_str!("../../../test_data/schedule/schedule.json");
+    let schedule: Schedule = serde_json::from_str(s).unwrap();
+
+    assert_eq!(schedule.name.as_deref(), Some("Weekly"));
+    assert_eq!(schedule.timezone.as_deref(), Some("Europe/Paris"));
+    assert!(schedule.enabled);
+    assert_eq!(
+        schedule.parse_cron().unwrap(),
+        "0 3 * * sun".parse().unwrap()
+    );
+}
@@ -0,0 +1,62 @@
+use crate::{
+    api::{
+        job::JobId,
+        schedule::{Cron, CronParseError},
+    },
+    declare_id_type,
+};
+
+declare_id_type! {
+    /// Unique id of a schedule
+    pub struct ScheduleId;
+}
+
+/// Type representing a schedule, which runs a job at the times given by a cron expression
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Schedule {
+    pub id: ScheduleId,
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Cron expression of when the job is run, see [`Self::parse_cron`]
+    pub cron: String,
+
+    /// Name of the timezone the cron expression is evaluated in, like `Europe/Paris`
+    ///
+    /// The timezone of xo-server is used if unset. Note that [`Cron::next_runs`] does not
+    /// resolve this name, the caller has to supply the matching UTC offset.
+    #[serde(default)]
+    pub timezone: Option<String>,
+    pub enabled: bool,
+    pub job_id: JobId,
+
+    /// Id of the user who owns the schedule
+    #[serde(default)]
+    pub user_id: Option<String>,
+}
+
+impl Schedule {
+    /// Parse the cron expression of the schedule
+    pub fn parse_cron(&self) -> Result<Cron, CronParseError> {
+        self.cron.parse()
+    }
+}
+
+/// Changes to apply to a schedule, unset fields are left unchanged
+#[derive(serde::Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleChanges {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cron: Option<Cron>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<JobId>,
+
+    /// `Some(None)` removes the name of the schedule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<Option<String>>,
+}
@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod tests;
+
+mod types;
+pub use types::{NewServer, ServerChanges, ServerId, ServerStatus, XenServer};
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::{
+    fmt,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use crate::{procedure_args, struct_to_map, RpcError};
+
+pub struct ServerProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl ServerProcedures {
+    /// How often [`Self::wait_until_connected`] polls the server
+    const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// Add a XAPI server, usually a pool master, returns the id of the added server
+    ///
+    /// xo-cli: server.add [label=<string>] host=<string> username=<string> password=<string> [autoConnect=<boolean>] [allowUnauthorized=<boolean>]
+    pub async fn add(&self, server: NewServer) -> Result<ServerId, RpcError> {
+        struct_to_map!(let params = server);
+
+        self.inner
+            .request("server.add", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Change settings of a server
+    ///
+    /// xo-cli: server.set id=<string> [label=<string>] [host=<string>] [username=<string>] [password=<string>] [allowUnauthorized=<boolean>] [readOnly=<boolean>]
+    pub async fn set(&self, server_id: ServerId, changes: ServerChanges) -> Result<(), RpcError> {
+        struct_to_map!(let params = changes);
+        let mut params = params;
+        params.insert("id", server_id.into());
+
+        self.inner
+            .request::<JsonValue>("server.set", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Remove a server, disconnecting from it
+    ///
+    /// xo-cli: server.remove id=<string>
+    pub async fn remove(&self, server_id: ServerId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => server_id };
+
+        self.inner
+            .request::<JsonValue>("server.remove", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Enable a server, which makes XO connect to it
+    ///
+    /// xo-cli: server.enable id=<string>
+    pub async fn enable(&self, server_id: ServerId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => server_id };
+
+        self.inner
+            .request::<JsonValue>("server.enable", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Disable a server, which makes XO disconnect from it
+    ///
+    /// xo-cli: server.disable id=<string>
+    pub async fn disable(&self, server_id: ServerId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => server_id };
+
+        self.inner
+            .request::<JsonValue>("server.disable", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get all servers
+    ///
+    /// xo-cli: server.getAll
+    pub async fn get_all(&self) -> Result<Vec<XenServer>, RpcError> {
+        self.inner
+            .request("server.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Wait for XO to connect to a server, for example one that was just added
+    ///
+    /// The servers are polled once per second until the server is connected, fails to
+    /// connect or `timeout` has passed.
+    ///
+    /// Example
+    /// ```no_run
+    /// # async fn example(con: xo_api_client::Client) {
+    /// use std::time::Duration;
+    /// use xo_api_client::api::server::NewServer;
+    ///
+    /// let server = NewServer::new("10.0.0.2".to_string(), "root".to_string(), "password");
+    /// let server_id = con.server.add(server).await.expect("Failed to add server");
+    ///
+    /// con.server
+    ///     .wait_until_connected(server_id, Duration::from_secs(60))
+    ///     .await
+    ///     .expect("Failed to connect to server");
+    /// # }
+    /// ```
+    pub async fn wait_until_connected(
+        &self,
+        server_id: ServerId,
+        timeout: Duration,
+    ) -> Result<XenServer, WaitForServerError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let server = self
+                .get_all()
+                .await
+                .map_err(WaitForServerError::Rpc)?
+                .into_iter()
+                .find(|server| server.id == server_id)
+                .ok_or(WaitForServerError::Vanished)?;
+
+            match server.status {
+                ServerStatus::Connected => return Ok(server),
+                ServerStatus::Disconnected if !server.enabled || server.error.is_some() => {
+                    log::warn!(
+                        "wait_until_connected: {:?} failed to connect: {:?}",
+                        server_id,
+                        server.error
+                    );
+                    return Err(WaitForServerError::ConnectionFailed(Box::new(server)));
+                }
+                _ => {}
+            }
+
+            if Instant::now() >= deadline {
+                return Err(WaitForServerError::Timeout);
+            }
+            tokio::time::sleep(Self::POLL_INTERVAL).await;
+        }
+    }
+}
+
+/// Error while waiting for a server to connect
+#[derive(Debug)]
+pub enum WaitForServerError {
+    /// The server is no longer in XO, it may have been removed
+    Vanished,
+
+    /// The server is disabled, or the last connection attempt failed. See
+    /// [`XenServer::error`]
+    ConnectionFailed(Box<XenServer>),
+
+    Timeout,
+    Rpc(RpcError),
+}
+
+impl fmt::Display for WaitForServerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WaitForServerError::Vanished => f.write_str("Server is no longer in XO"),
+            WaitForServerError::ConnectionFailed(server) => match &server.error {
+                Some(error) => match error["message"].as_str() {
+                    Some(message) => write!(f, "Failed to connect to {}: {}", server.host, message),
+                    None => write!(f, "Failed to connect to {}: {}", server.host, error),
+                },
+                None => write!(f, "Server {} is disabled", server.host),
+            },
+            WaitForServerError::Timeout => f.write_str("Timed out waiting for the server"),
+            WaitForServerError::Rpc(e) => write!(f, "Failed to get the server: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for WaitForServerError {}
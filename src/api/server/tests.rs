@@ -0,0 +1,60 @@
+use super::{NewServer, ServerChanges, ServerStatus, WaitForServerError, XenServer};
+
+#[test]
+fn servers() {
+    let s = include_str!("../../../test_data/server/servers.json");
+    let servers: Vec<XenServer> = serde_json::from_str(s).unwrap();
+
+    assert_eq!(servers.len(), 2);
+
+    let connected = &servers[0];
+    assert!(connected.is_connected());
+    assert_eq!(connected.label.as_deref(), Some("Lab pool"));
+    assert!(connected.allow_unauthorized);
+    assert!(connected.error.is_none());
+
+    let failed = &servers[1];
+    assert_eq!(failed.status, ServerStatus::Disconnected);
+    assert!(failed.read_only);
+    assert!(failed.pool_id.is_none());
+    assert_eq!(failed.error.as_ref().unwrap()["code"], "ECONNREFUSED");
+
+    // Servers added by older versions of XO lack `enabled`
+    let old: XenServer = serde_json::from_str(
+        r#"{ "id": "a", "host": "10.0.0.4", "username": "root", "status": "connecting" }"#,
+    )
+    .unwrap();
+    assert!(old.enabled);
+
+    let error = WaitForServerError::ConnectionFailed(Box::new(failed.clone()));
+    assert_eq!(
+        error.to_string(),
+        "Failed to connect to 10.0.0.3: connect ECONNREFUSED 10.0.0.3:443"
+    );
+}
+
+#[test]
+fn new_server() {
+    let mut server = NewServer::new("10.0.0.2".to_string(), "root".to_string(), "hunter2");
+    server.allow_unauthorized = Some(true);
+
+    assert!(!format!("{:?}", server).contains("hunter2"));
+    assert_eq!(
+        serde_json::to_value(&server).unwrap(),
+        serde_json::json!({
+            "host": "10.0.0.2",
+            "username": "root",
+            "password": "hunter2",
+            "allowUnauthorized": true,
+        })
+    );
+
+    let changes = ServerChanges {
+        read_only: Some(true),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(&changes).unwrap(),
+        serde_json::json!({ "readOnly": true })
+    );
+}
@@ -0,0 +1,111 @@
+use jsonrpsee_types::JsonValue;
+
+use crate::{credentials::Secret, declare_id_type};
+
+declare_id_type! {
+    /// Unique id of a XAPI server connected to XO, usually a pool master
+    pub struct ServerId;
+}
+
+/// Type representing a XAPI server, usually a pool master, that XO connects to
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct XenServer {
+    pub id: ServerId,
+
+    #[serde(default)]
+    pub label: Option<String>,
+    pub host: String,
+    pub username: String,
+    pub status: ServerStatus,
+
+    /// Whether XO should connect to the server, disabled servers are never connected
+    ///
+    /// Servers added by older versions of XO lack this field, they are enabled
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    #[serde(default)]
+    pub read_only: bool,
+
+    /// Accept self signed certificates
+    #[serde(default)]
+    pub allow_unauthorized: bool,
+
+    /// Id of the pool of the server, only known once connected
+    #[serde(default)]
+    pub pool_id: Option<String>,
+
+    /// Error from the last connection attempt, if any
+    #[serde(default)]
+    pub error: Option<JsonValue>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl XenServer {
+    pub fn is_connected(&self) -> bool {
+        self.status == ServerStatus::Connected
+    }
+}
+
+/// Connection status of a [`XenServer`]
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ServerStatus {
+    Connected,
+    Connecting,
+    Disconnected,
+}
+
+/// Server to add to XO, see [`super::ServerProcedures::add`]
+#[derive(serde::Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NewServer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    pub host: String,
+    pub username: String,
+    pub password: Secret,
+
+    /// Whether XO should connect right away, defaults to `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_connect: Option<bool>,
+
+    /// Accept self signed certificates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_unauthorized: Option<bool>,
+}
+
+impl NewServer {
+    pub fn new(host: String, username: String, password: impl Into<Secret>) -> Self {
+        NewServer {
+            label: None,
+            host,
+            username,
+            password: password.into(),
+            auto_connect: None,
+            allow_unauthorized: None,
+        }
+    }
+}
+
+/// Changes to apply to a server, unset fields are left unchanged
+#[derive(serde::Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerChanges {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<Secret>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_unauthorized: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+}
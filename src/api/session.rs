@@ -1,40 +1,101 @@
-use std::sync::Arc;
+#[cfg(test)]
+mod tests;
 
-use jsonrpsee_types::{traits::Client, v2::params::ParamsSer};
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use jsonrpsee_types::{traits::Client, v2::params::ParamsSer, JsonValue};
 use jsonrpsee_ws_client::WsClient;
 
-use crate::{credentials::Credentials, RpcError};
+use crate::{
+    api::user::User,
+    credentials::{Credentials, EmailAndPassword, Token},
+    RpcError,
+};
 
 pub struct SessionProcedures {
     pub(crate) inner: Arc<WsClient>,
+
+    /// Whether the client is signed in, shared with [`crate::Client`]
+    pub(crate) authenticated: Arc<AtomicBool>,
 }
 
 impl SessionProcedures {
     /// Sign in to xo-server, this is required for access to most of the other methods
     ///
+    /// Returns the user the client is now signed in as
+    ///
     /// xo-cli: session.signIn
-    pub async fn sign_in(&self, credentials: impl Into<Credentials>) -> Result<(), RpcError> {
-        log::debug!("Signing in...");
+    pub async fn sign_in(&self, credentials: impl Into<Credentials>) -> Result<User, RpcError> {
+        self.sign_in_with("session.signIn", credentials.into())
+            .await
+    }
 
-        #[derive(serde::Serialize)]
-        pub struct Credentials {
-            email: String,
-            password: String,
-        }
+    /// Sign in to xo-server with email and password
+    ///
+    /// Returns the user the client is now signed in as
+    ///
+    /// xo-cli: session.signInWithPassword email=<string> password=<string>
+    pub async fn sign_in_with_password(
+        &self,
+        credentials: EmailAndPassword,
+    ) -> Result<User, RpcError> {
+        self.sign_in_with("session.signInWithPassword", credentials.into())
+            .await
+    }
 
-        let _: SigninResponse = self
-            .inner
-            .request(
-                "session.signIn",
-                Some(ParamsSer::Map(credentials.into().into())),
-            )
+    /// Sign in to xo-server with an authentication token
+    ///
+    /// Returns the user the client is now signed in as
+    ///
+    /// xo-cli: session.signInWithToken token=<string>
+    pub async fn sign_in_with_token(&self, token: Token) -> Result<User, RpcError> {
+        self.sign_in_with("session.signInWithToken", token.into())
+            .await
+    }
+
+    /// Sign out, most methods are unavailable until signing in again
+    ///
+    /// xo-cli: session.signOut
+    pub async fn sign_out(&self) -> Result<(), RpcError> {
+        log::debug!("Signing out...");
+
+        self.inner
+            .request::<JsonValue>("session.signOut", Some(ParamsSer::Map(BTreeMap::new())))
             .await?;
+        self.authenticated.store(false, Ordering::SeqCst);
 
-        log::debug!("Signed in");
+        log::debug!("Signed out");
 
         Ok(())
     }
-}
 
-#[derive(serde::Deserialize)]
-struct SigninResponse {}
+    /// Get the user the client is signed in as
+    ///
+    /// xo-cli: session.getUser
+    pub async fn get_user(&self) -> Result<User, RpcError> {
+        self.inner
+            .request("session.getUser", Some(ParamsSer::Map(BTreeMap::new())))
+            .await
+    }
+
+    async fn sign_in_with(&self, method: &str, credentials: Credentials) -> Result<User, RpcError> {
+        log::debug!("Signing in with {:?}...", credentials);
+
+        let params = BTreeMap::from(&credentials);
+        let user: User = self
+            .inner
+            .request(method, Some(ParamsSer::Map(params)))
+            .await?;
+        self.authenticated.store(true, Ordering::SeqCst);
+
+        log::debug!("Signed in as {}", user.email);
+
+        Ok(user)
+    }
+}
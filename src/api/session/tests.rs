@@ -0,0 +1,96 @@
+use futures::io::{BufReader, BufWriter};
+use jsonrpsee_types::JsonValue;
+use soketto::handshake::{server::Response, Server};
+use tokio::net::TcpListener;
+use tokio_util::compat::TokioAsyncReadCompatExt;
+
+use crate::{credentials::EmailAndPassword, Client};
+
+/// Serve a single websocket connection, answering sign ins with the password `hunter2`
+/// and sign outs, returning the address to connect to
+async fn serve() -> std::net::SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let (socket, _) = listener.accept().await.unwrap();
+        let mut server = Server::new(BufReader::new(BufWriter::new(socket.compat())));
+        let key = server.receive_request().await.unwrap().key();
+        let accept = Response::Accept {
+            key,
+            protocol: None,
+        };
+        server.send_response(&accept).await.unwrap();
+        let (mut sender, mut receiver) = server.into_builder().finish();
+
+        let mut message = Vec::new();
+        while receiver.receive_data(&mut message).await.is_ok() {
+            let request: JsonValue = serde_json::from_slice(&message).unwrap();
+            message.clear();
+
+            let result = match (request["method"].as_str(), &request["params"]["password"]) {
+                (Some("session.signInWithPassword"), password) if password == "hunter2" => {
+                    let user = include_str!("../../../test_data/user/user.json");
+                    serde_json::json!({ "result": serde_json::from_str::<JsonValue>(user).unwrap() })
+                }
+                (Some("session.signInWithPassword"), _) => {
+                    serde_json::json!({ "error": { "code": 3, "message": "invalid credentials" } })
+                }
+                (Some("session.signOut"), _) => serde_json::json!({ "result": true }),
+                (method, _) => panic!("unexpected method {:?}", method),
+            };
+            let mut response = result;
+            response["jsonrpc"] = "2.0".into();
+            response["id"] = request["id"].clone();
+
+            sender.send_text(response.to_string()).await.unwrap();
+            sender.flush().await.unwrap();
+        }
+    });
+
+    addr
+}
+
+#[tokio::test]
+async fn authenticated() {
+    let addr = serve().await;
+    let client = Client::connect(&format!("ws://{}/api/", addr))
+        .await
+        .unwrap();
+    assert!(!client.is_authenticated());
+
+    let wrong = EmailAndPassword {
+        email: "jane.doe@example.com".to_string(),
+        password: "wrong".into(),
+    };
+    assert!(client.session.sign_in_with_password(wrong).await.is_err());
+    assert!(!client.is_authenticated());
+
+    let credentials = EmailAndPassword {
+        email: "jane.doe@example.com".to_string(),
+        password: "hunter2".into(),
+    };
+    let user = client
+        .session
+        .sign_in_with_password(credentials.clone())
+        .await
+        .unwrap();
+    assert_eq!(user.email, "jane.doe@example.com");
+    assert!(client.is_authenticated());
+
+    client.session.sign_out().await.unwrap();
+    assert!(!client.is_authenticated());
+
+    // A failed sign in does not change the state
+    client
+        .session
+        .sign_in_with_password(credentials)
+        .await
+        .unwrap();
+    let wrong = EmailAndPassword {
+        email: "jane.doe@example.com".to_string(),
+        password: "wrong".into(),
+    };
+    assert!(client.session.sign_in_with_password(wrong).await.is_err());
+    assert!(client.is_authenticated());
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer};
+use jsonrpsee_ws_client::WsClient;
+
+use crate::{
+    api::stats::{Granularity, SrStats},
+    declare_id_type, procedure_args, RpcError,
+};
+
+declare_id_type! {
+    /// Unique id of a storage repository
+    pub struct SrId;
+}
+
+pub struct SrProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl SrProcedures {
+    /// Get performance statistics of a storage repository
+    ///
+    /// xo-cli: sr.stats id=<string> [granularity=<string>]
+    pub async fn stats(&self, sr_id: SrId, granularity: Granularity) -> Result<SrStats, RpcError> {
+        let params = procedure_args! { "id" => sr_id, "granularity" => granularity };
+
+        self.inner
+            .request("sr.stats", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
@@ -0,0 +1,363 @@
+#[cfg(test)]
+mod tests;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use jsonrpsee_types::JsonValue;
+
+/// Resolution of performance statistics
+///
+/// The coarser the granularity, the longer back in time the statistics go
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Granularity {
+    /// One sample every 5 seconds
+    Seconds,
+
+    /// One sample every minute
+    Minutes,
+
+    /// One sample every hour
+    Hours,
+
+    /// One sample every day
+    Days,
+}
+
+impl From<Granularity> for JsonValue {
+    fn from(granularity: Granularity) -> Self {
+        serde_json::to_value(granularity).unwrap()
+    }
+}
+
+/// Series of samples taken at a fixed interval
+///
+/// Samples may be missing, for example when a VM was not running
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeSeries {
+    /// Unix timestamp of the last sample
+    pub end_timestamp: u64,
+
+    /// Number of seconds between two samples
+    pub interval: u64,
+    pub values: Vec<Option<f64>>,
+}
+
+impl TimeSeries {
+    fn new(end_timestamp: u64, interval: u64, values: Vec<Option<f64>>) -> Self {
+        TimeSeries {
+            end_timestamp,
+            interval,
+            values,
+        }
+    }
+
+    /// Unix timestamp of the first sample
+    pub fn start_timestamp(&self) -> u64 {
+        self.timestamp(0)
+    }
+
+    /// Unix timestamp of the sample at `index`
+    pub fn timestamp(&self, index: usize) -> u64 {
+        let samples_after = self.values.len().saturating_sub(index + 1) as u64;
+        self.end_timestamp
+            .saturating_sub(samples_after * self.interval)
+    }
+
+    /// Iterator of all samples along with their unix timestamps
+    pub fn iter(&self) -> impl Iterator<Item = (u64, Option<f64>)> + '_ {
+        self.values
+            .iter()
+            .enumerate()
+            .map(move |(i, value)| (self.timestamp(i), *value))
+    }
+
+    /// The most recent sample that is not missing
+    pub fn last(&self) -> Option<(u64, f64)> {
+        let index = self.values.iter().rposition(Option::is_some)?;
+        Some((self.timestamp(index), self.values[index]?))
+    }
+}
+
+/// Received and transmitted bytes per second of a network interface
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkStats {
+    pub rx: Option<TimeSeries>,
+    pub tx: Option<TimeSeries>,
+}
+
+/// Statistics of a virtual disk or storage repository
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiskStats {
+    /// Bytes read per second
+    pub read: Option<TimeSeries>,
+
+    /// Bytes written per second
+    pub write: Option<TimeSeries>,
+
+    /// Read operations per second
+    pub iops_read: Option<TimeSeries>,
+
+    /// Write operations per second
+    pub iops_write: Option<TimeSeries>,
+
+    /// Read latency in milliseconds
+    pub latency_read: Option<TimeSeries>,
+
+    /// Write latency in milliseconds
+    pub latency_write: Option<TimeSeries>,
+}
+
+/// Performance statistics of a VM
+///
+/// xo-cli: vm.stats
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(from = "RawStats<RawVmStats>")]
+pub struct VmStats {
+    /// CPU usage per core, from 0.0 to 1.0
+    pub cpus: Vec<TimeSeries>,
+
+    /// Memory size in bytes
+    pub memory: Option<TimeSeries>,
+
+    /// Free memory in bytes as reported by the guest tools
+    pub memory_free: Option<TimeSeries>,
+
+    /// Network statistics per VIF, indexed by device number
+    pub vifs: BTreeMap<String, NetworkStats>,
+
+    /// Disk statistics per VBD, indexed by device name like `xvda`
+    pub vbds: BTreeMap<String, DiskStats>,
+}
+
+/// Performance statistics of a host
+///
+/// xo-cli: host.stats
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(from = "RawStats<RawHostStats>")]
+pub struct HostStats {
+    /// CPU usage per core, from 0.0 to 1.0
+    pub cpus: Vec<TimeSeries>,
+
+    /// Load average of the host
+    pub load: Option<TimeSeries>,
+
+    /// Memory size in bytes
+    pub memory: Option<TimeSeries>,
+
+    /// Free memory in bytes
+    pub memory_free: Option<TimeSeries>,
+
+    /// Network statistics per PIF, indexed by device number
+    pub pifs: BTreeMap<String, NetworkStats>,
+}
+
+/// Performance statistics of a storage repository
+///
+/// xo-cli: sr.stats
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(from = "RawStats<RawSrStats>")]
+pub struct SrStats {
+    pub disk: DiskStats,
+
+    /// IO wait per host, indexed by host id
+    pub iowait: BTreeMap<String, TimeSeries>,
+}
+
+type RawSeries = Vec<Option<f64>>;
+
+#[derive(serde::Deserialize)]
+struct RawStats<S> {
+    #[serde(rename = "endTimestamp")]
+    end_timestamp: u64,
+    interval: u64,
+    stats: S,
+}
+
+#[derive(serde::Deserialize, Default)]
+#[serde(default)]
+struct RawRxTx {
+    rx: BTreeMap<String, RawSeries>,
+    tx: BTreeMap<String, RawSeries>,
+}
+
+#[derive(serde::Deserialize, Default)]
+#[serde(default)]
+struct RawReadWrite<T> {
+    r: T,
+    w: T,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawVmStats {
+    #[serde(default)]
+    cpus: BTreeMap<String, RawSeries>,
+    memory: Option<RawSeries>,
+    memory_free: Option<RawSeries>,
+    #[serde(default)]
+    vifs: RawRxTx,
+    #[serde(default)]
+    xvds: RawReadWrite<BTreeMap<String, RawSeries>>,
+    #[serde(default)]
+    iops: RawReadWrite<BTreeMap<String, RawSeries>>,
+    #[serde(default)]
+    latency: RawReadWrite<BTreeMap<String, RawSeries>>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawHostStats {
+    #[serde(default)]
+    cpus: BTreeMap<String, RawSeries>,
+    load: Option<RawSeries>,
+    memory: Option<RawSeries>,
+    memory_free: Option<RawSeries>,
+    #[serde(default)]
+    pifs: RawRxTx,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawSrStats {
+    #[serde(default)]
+    io_throughput: RawReadWrite<Option<RawSeries>>,
+    #[serde(default)]
+    iops: RawReadWrite<Option<RawSeries>>,
+    #[serde(default)]
+    latency: RawReadWrite<Option<RawSeries>>,
+    #[serde(default)]
+    iowait: BTreeMap<String, RawSeries>,
+}
+
+/// Turn CPU series indexed by core number into a list ordered by core number
+fn cpus(
+    series: BTreeMap<String, RawSeries>,
+    to_series: impl Fn(RawSeries) -> TimeSeries,
+) -> Vec<TimeSeries> {
+    let mut cpus: Vec<(u32, RawSeries)> = series
+        .into_iter()
+        .filter_map(|(core, values)| match core.parse() {
+            Ok(core) => Some((core, values)),
+            Err(e) => {
+                log::warn!("Invalid CPU core index in stats: {}, {:?}", core, e);
+                None
+            }
+        })
+        .collect();
+    cpus.sort_by_key(|(core, _values)| *core);
+
+    cpus.into_iter()
+        .map(|(_core, values)| to_series(values))
+        .collect()
+}
+
+fn network(
+    RawRxTx { mut rx, mut tx }: RawRxTx,
+    to_series: impl Fn(RawSeries) -> TimeSeries,
+) -> BTreeMap<String, NetworkStats> {
+    let devices: BTreeSet<String> = rx.keys().chain(tx.keys()).cloned().collect();
+
+    devices
+        .into_iter()
+        .map(|device| {
+            let stats = NetworkStats {
+                rx: rx.remove(&device).map(&to_series),
+                tx: tx.remove(&device).map(&to_series),
+            };
+            (device, stats)
+        })
+        .collect()
+}
+
+impl From<RawStats<RawVmStats>> for VmStats {
+    fn from(raw: RawStats<RawVmStats>) -> Self {
+        let to_series = |values| TimeSeries::new(raw.end_timestamp, raw.interval, values);
+        let RawVmStats {
+            cpus: raw_cpus,
+            memory,
+            memory_free,
+            vifs,
+            mut xvds,
+            mut iops,
+            mut latency,
+        } = raw.stats;
+
+        let devices: BTreeSet<String> = [&xvds, &iops, &latency]
+            .iter()
+            .flat_map(|rw| rw.r.keys().chain(rw.w.keys()))
+            .cloned()
+            .collect();
+
+        let vbds = devices
+            .into_iter()
+            .map(|device| {
+                let stats = DiskStats {
+                    read: xvds.r.remove(&device).map(to_series),
+                    write: xvds.w.remove(&device).map(to_series),
+                    iops_read: iops.r.remove(&device).map(to_series),
+                    iops_write: iops.w.remove(&device).map(to_series),
+                    latency_read: latency.r.remove(&device).map(to_series),
+                    latency_write: latency.w.remove(&device).map(to_series),
+                };
+                (device, stats)
+            })
+            .collect();
+
+        VmStats {
+            cpus: cpus(raw_cpus, to_series),
+            memory: memory.map(to_series),
+            memory_free: memory_free.map(to_series),
+            vifs: network(vifs, to_series),
+            vbds,
+        }
+    }
+}
+
+impl From<RawStats<RawHostStats>> for HostStats {
+    fn from(raw: RawStats<RawHostStats>) -> Self {
+        let to_series = |values| TimeSeries::new(raw.end_timestamp, raw.interval, values);
+        let RawHostStats {
+            cpus: raw_cpus,
+            load,
+            memory,
+            memory_free,
+            pifs,
+        } = raw.stats;
+
+        HostStats {
+            cpus: cpus(raw_cpus, to_series),
+            load: load.map(to_series),
+            memory: memory.map(to_series),
+            memory_free: memory_free.map(to_series),
+            pifs: network(pifs, to_series),
+        }
+    }
+}
+
+impl From<RawStats<RawSrStats>> for SrStats {
+    fn from(raw: RawStats<RawSrStats>) -> Self {
+        let to_series = |values| TimeSeries::new(raw.end_timestamp, raw.interval, values);
+        let RawSrStats {
+            io_throughput,
+            iops,
+            latency,
+            iowait,
+        } = raw.stats;
+
+        SrStats {
+            disk: DiskStats {
+                read: io_throughput.r.map(to_series),
+                write: io_throughput.w.map(to_series),
+                iops_read: iops.r.map(to_series),
+                iops_write: iops.w.map(to_series),
+                latency_read: latency.r.map(to_series),
+                latency_write: latency.w.map(to_series),
+            },
+            iowait: iowait
+                .into_iter()
+                .map(|(host, values)| (host, to_series(values)))
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use jsonrpsee_types::JsonValue;
+
+use super::{Granularity, HostStats, SrStats, VmStats};
+
+#[test]
+fn vm() {
+    let s = include_str!("../../../test_data/stats/vm.json");
+    let stats: VmStats = serde_json::from_str(s).unwrap();
+
+    assert_eq!(stats.cpus.len(), 2);
+    assert_eq!(stats.cpus[1].values, vec![Some(0.02), None, Some(0.03)]);
+    assert_eq!(
+        stats.cpus[0].iter().collect::<Vec<_>>(),
+        vec![
+            (1623464690, Some(0.05)),
+            (1623464695, Some(0.1)),
+            (1623464700, Some(0.07))
+        ]
+    );
+    assert_eq!(stats.cpus[0].start_timestamp(), 1623464690);
+
+    let memory_free = stats.memory_free.unwrap();
+    assert_eq!(memory_free.last(), Some((1623464700, 1006632960.0)));
+
+    assert_eq!(stats.vifs.len(), 2);
+    assert!(stats.vifs["0"].rx.is_some());
+    assert!(stats.vifs["0"].tx.is_some());
+    assert!(stats.vifs["1"].rx.is_none());
+
+    let xvda = &stats.vbds["xvda"];
+    assert_eq!(
+        xvda.read.as_ref().unwrap().values,
+        vec![Some(0.0), Some(8192.0), Some(4096.0)]
+    );
+    assert_eq!(
+        xvda.iops_write.as_ref().unwrap().values,
+        vec![Some(5.0), Some(12.0), Some(7.0)]
+    );
+    assert_eq!(xvda.latency_read.as_ref().unwrap().interval, 5);
+}
+
+#[test]
+fn host() {
+    let s = include_str!("../../../test_data/stats/host.json");
+    let stats: HostStats = serde_json::from_str(s).unwrap();
+
+    // Cores are ordered numerically, not lexicographically
+    assert_eq!(stats.cpus.len(), 4);
+    assert_eq!(stats.cpus[3].values, vec![Some(0.2), Some(0.22)]);
+
+    let load = stats.load.unwrap();
+    assert_eq!(load.start_timestamp(), 1623459600);
+    assert_eq!(load.last(), Some((1623463200, 1.2)));
+    assert_eq!(stats.pifs["0"].tx.as_ref().unwrap().values.len(), 2);
+}
+
+#[test]
+fn sr() {
+    let s = include_str!("../../../test_data/stats/sr.json");
+    let stats: SrStats = serde_json::from_str(s).unwrap();
+
+    assert_eq!(
+        stats.disk.write.unwrap().values,
+        vec![Some(8192.0), Some(16384.0)]
+    );
+    assert_eq!(stats.disk.iops_read.unwrap().interval, 60);
+    assert_eq!(stats.iowait.len(), 1);
+}
+
+#[test]
+fn granularity() {
+    assert_eq!(JsonValue::from(Granularity::Seconds), "seconds");
+    assert_eq!(JsonValue::from(Granularity::Days), "days");
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests;
+
+mod types;
+pub use types::{
+    MethodInfo, MethodSignature, ParamSchema, ServerVersion, ServerVersionParseError,
+    TESTED_SERVER_VERSIONS,
+};
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer};
+use jsonrpsee_ws_client::WsClient;
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::{procedure_args, RpcError};
+
+pub struct SystemProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl SystemProcedures {
+    /// Get the version of xo-server, like `5.98.1`
+    ///
+    /// See [`ServerVersion`] for a parsed version
+    ///
+    /// xo-cli: system.getServerVersion
+    pub async fn get_server_version(&self) -> Result<String, RpcError> {
+        self.inner
+            .request(
+                "system.getServerVersion",
+                Some(ParamsSer::Map(procedure_args! {})),
+            )
+            .await
+    }
+
+    /// Get the version of the API, note that the API is not versioned in a stable way
+    ///
+    /// xo-cli: system.getVersion
+    pub async fn get_version(&self) -> Result<String, RpcError> {
+        self.inner
+            .request(
+                "system.getVersion",
+                Some(ParamsSer::Map(procedure_args! {})),
+            )
+            .await
+    }
+
+    /// Get the timezone of the server, like `Europe/Stockholm`
+    ///
+    /// xo-cli: system.getServerTimezone
+    pub async fn get_server_timezone(&self) -> Result<String, RpcError> {
+        self.inner
+            .request(
+                "system.getServerTimezone",
+                Some(ParamsSer::Map(procedure_args! {})),
+            )
+            .await
+    }
+
+    /// Get the names of all API methods
+    ///
+    /// xo-cli: system.listMethods
+    pub async fn list_methods(&self) -> Result<Vec<String>, RpcError> {
+        self.inner
+            .request(
+                "system.listMethods",
+                Some(ParamsSer::Map(procedure_args! {})),
+            )
+            .await
+    }
+
+    /// Get the signature of an API method, like `vm.start`
+    ///
+    /// xo-cli: system.methodSignature
+    pub async fn method_signature(&self, method: &str) -> Result<Vec<MethodSignature>, RpcError> {
+        let params = procedure_args! { "method" => method };
+
+        self.inner
+            .request("system.methodSignature", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get descriptions of all API methods, indexed by method name
+    ///
+    /// xo-cli: system.getMethodsInfo
+    pub async fn get_methods_info(&self) -> Result<BTreeMap<String, MethodInfo>, RpcError> {
+        self.inner
+            .request(
+                "system.getMethodsInfo",
+                Some(ParamsSer::Map(procedure_args! {})),
+            )
+            .await
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::BTreeMap;
+
+use super::{MethodInfo, ServerVersion};
+
+#[test]
+fn server_versions() {
+    let version: ServerVersion = "5.98.1".parse().unwrap();
+    assert_eq!(version, ServerVersion::new(5, 98, 1));
+    assert_eq!(version.to_string(), "5.98.1");
+    assert!(version.is_tested());
+
+    assert_eq!(
+        "5.100.0-beta.2".parse::<ServerVersion>().unwrap(),
+        ServerVersion::new(5, 100, 0)
+    );
+    assert!(ServerVersion::new(5, 9, 0) < ServerVersion::new(5, 10, 0));
+    assert!(!ServerVersion::new(4, 20, 0).is_tested());
+    assert!(!ServerVersion::new(6, 0, 0).is_tested());
+
+    assert!("5.98".parse::<ServerVersion>().is_err());
+    assert!("5.98.1.2".parse::<ServerVersion>().is_err());
+    assert!("latest".parse::<ServerVersion>().is_err());
+}
+
+#[test]
+fn methods_info() {
+    let s = include_str!("../../../test_data/system/methods_info.json");
+    let methods: BTreeMap<String, MethodInfo> = serde_json::from_str(s).unwrap();
+
+    assert_eq!(methods.len(), 4);
+    assert!(methods["system.getServerVersion"].params.is_empty());
+    assert!(methods["system.getServerVersion"].permission.is_none());
+
+    let snapshot = &methods["vm.snapshot"];
+    assert_eq!(snapshot.permission.as_deref(), Some("admin"));
+    assert_eq!(snapshot.params["id"].signature("id"), "id=<string>");
+    assert_eq!(
+        snapshot.params["saveMemory"].signature("saveMemory"),
+        "[saveMemory=<boolean>]"
+    );
+
+    let granularity = &methods["vm.stats"].params["granularity"];
+    assert_eq!(granularity.allowed_values.as_ref().unwrap().len(), 4);
+
+    let create = &methods["vm.create"];
+    assert_eq!(
+        create.params["coresPerSocket"].signature("coresPerSocket"),
+        "[coresPerSocket=<string|number>]"
+    );
+    let vif = create.params["VIFs"].items.as_ref().unwrap();
+    let properties = vif.properties.as_ref().unwrap();
+    assert!(properties["mac"].optional);
+    assert_eq!(create.params["cpus"].other["minimum"], 1);
+}
@@ -0,0 +1,166 @@
+use std::{collections::BTreeMap, fmt, ops::RangeInclusive, str::FromStr};
+
+use jsonrpsee_types::JsonValue;
+
+/// Version of xo-server, as returned by [`super::SystemProcedures::get_server_version`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ServerVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+/// Versions of xo-server the bindings target
+///
+/// Note that this is not backed by automated tests against running servers, the
+/// fixtures in `test_data` are not tied to a specific release. Versions in the range are
+/// expected to work, the ones outside of it may or may not.
+///
+/// See [`crate::Client::connect_with_version_check`]
+pub const TESTED_SERVER_VERSIONS: RangeInclusive<ServerVersion> =
+    RangeInclusive::new(ServerVersion::new(5, 59, 0), ServerVersion::new(5, 109, 0));
+
+impl ServerVersion {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        ServerVersion {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Whether this version is in [`TESTED_SERVER_VERSIONS`]
+    pub fn is_tested(&self) -> bool {
+        TESTED_SERVER_VERSIONS.contains(self)
+    }
+}
+
+/// Error returned when parsing an invalid server version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerVersionParseError(String);
+
+impl fmt::Display for ServerVersionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid server version: {}", self.0)
+    }
+}
+
+impl std::error::Error for ServerVersionParseError {}
+
+impl FromStr for ServerVersion {
+    type Err = ServerVersionParseError;
+
+    /// Parse versions like `5.98.1`, pre-release and build suffixes like `-beta.1` are
+    /// ignored
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ServerVersionParseError(s.to_string());
+
+        let version = s.trim().trim_start_matches('v');
+        let version = version.split(['-', '+']).next().unwrap_or_default();
+        let mut parts = version.split('.').map(|part| part.parse::<u32>());
+
+        let mut next = || parts.next().ok_or_else(err)?.map_err(|_| err());
+        let parsed = ServerVersion::new(next()?, next()?, next()?);
+        if parts.next().is_some() {
+            return Err(err());
+        }
+
+        Ok(parsed)
+    }
+}
+
+impl fmt::Display for ServerVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Description of an API method, as returned by [`super::SystemProcedures::get_methods_info`]
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct MethodInfo {
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Parameters of the method by name
+    #[serde(default)]
+    pub params: BTreeMap<String, ParamSchema>,
+
+    /// Permission required to call the method, `None` if no permission is required
+    #[serde(default)]
+    pub permission: Option<String>,
+}
+
+/// Schema of an API method parameter
+///
+/// This is a subset of JSON schema, as used by xo-server to validate parameters
+#[derive(serde::Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ParamSchema {
+    /// Allowed types, like `string` or `number`. Empty means any type is allowed
+    #[serde(default, rename = "type", deserialize_with = "one_or_many")]
+    pub types: Vec<String>,
+
+    #[serde(default)]
+    pub optional: bool,
+
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Allowed values, if restricted
+    #[serde(default, rename = "enum")]
+    pub allowed_values: Option<Vec<JsonValue>>,
+
+    /// Schema of the items, for arrays
+    #[serde(default)]
+    pub items: Option<Box<ParamSchema>>,
+
+    /// Schemas of the properties, for objects
+    #[serde(default)]
+    pub properties: Option<BTreeMap<String, ParamSchema>>,
+
+    /// Any other JSON schema keywords, like `minimum` or `pattern`
+    #[serde(flatten)]
+    pub other: BTreeMap<String, JsonValue>,
+}
+
+impl ParamSchema {
+    /// Signature of the parameter in the format xo-cli uses, like `[name=<string|number>]`
+    pub fn signature(&self, name: &str) -> String {
+        let types = if self.types.is_empty() {
+            "any".to_string()
+        } else {
+            self.types.join("|")
+        };
+
+        if self.optional {
+            format!("[{}=<{}>]", name, types)
+        } else {
+            format!("{}=<{}>", name, types)
+        }
+    }
+}
+
+/// Signature of an API method, as returned by [`super::SystemProcedures::method_signature`]
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct MethodSignature {
+    pub name: String,
+
+    #[serde(default)]
+    pub description: Option<String>,
+
+    #[serde(default)]
+    pub params: BTreeMap<String, ParamSchema>,
+}
+
+fn one_or_many<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match serde::Deserialize::deserialize(deserializer)? {
+        OneOrMany::One(one) => vec![one],
+        OneOrMany::Many(many) => many,
+    })
+}
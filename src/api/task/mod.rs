@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod tests;
+
+mod types;
+pub use types::{Task, TaskId, TaskStatus};
+
+use futures::Stream;
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer};
+use jsonrpsee_ws_client::WsClient;
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    api::xo::{GetSingleObjectError, XoProcedures},
+    procedure_args, RpcError,
+};
+
+pub struct TaskProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl TaskProcedures {
+    /// Cancel a running task
+    ///
+    /// Note that the task is not guaranteed to stop immediately, use [`TaskHandle::wait`]
+    /// to know when it actually has stopped.
+    ///
+    /// xo-cli: task.cancel id=<string>
+    pub async fn cancel(&self, task_id: TaskId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => task_id };
+
+        self.inner
+            .request::<bool>("task.cancel", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Destroy a task, removing it from the server
+    ///
+    /// xo-cli: task.destroy id=<string>
+    pub async fn destroy(&self, task_id: TaskId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => task_id };
+
+        self.inner
+            .request::<bool>("task.destroy", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get a handle used to follow the progress of the task with the specified id
+    pub fn handle(&self, task_id: TaskId) -> TaskHandle {
+        TaskHandle {
+            xo: XoProcedures {
+                inner: Arc::clone(&self.inner),
+            },
+            id: task_id,
+            poll_interval: TaskHandle::DEFAULT_POLL_INTERVAL,
+        }
+    }
+}
+
+/// Handle to a task running on the server
+///
+/// The state of the task is polled from the server, see [`TaskHandle::with_poll_interval`]
+pub struct TaskHandle {
+    xo: XoProcedures,
+    id: TaskId,
+    poll_interval: Duration,
+}
+
+impl TaskHandle {
+    const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// Id of the task
+    pub fn id(&self) -> &TaskId {
+        &self.id
+    }
+
+    /// Set how often the server should be polled for task updates, defaults to once per second
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Get current state of the task
+    pub async fn get(&self) -> Result<Task, TaskError> {
+        match self.xo.get_object::<Task>(self.id.clone()).await {
+            Ok(Some(task)) => Ok(task),
+            Ok(None) => Err(TaskError::Vanished),
+            Err(GetSingleObjectError::MultipleMatches) => Err(TaskError::MultipleMatches),
+            Err(GetSingleObjectError::Rpc(e)) => Err(TaskError::Rpc(e)),
+        }
+    }
+
+    /// Stream of task updates
+    ///
+    /// A new item is produced every time the status or progress of the task changes. The
+    /// stream ends after the item where the task is finished.
+    pub fn progress(&self) -> impl Stream<Item = Result<Task, TaskError>> + '_ {
+        struct State {
+            last: Option<(TaskStatus, f64)>,
+            done: bool,
+        }
+
+        let state = State {
+            last: None,
+            done: false,
+        };
+
+        futures::stream::unfold(state, move |mut state| async move {
+            if state.done {
+                return None;
+            }
+
+            loop {
+                let task = match self.get().await {
+                    Ok(task) => task,
+                    Err(e) => {
+                        state.done = true;
+                        return Some((Err(e), state));
+                    }
+                };
+
+                let current = (task.status, task.progress);
+                if state.last != Some(current) {
+                    state.last = Some(current);
+                    state.done = task.is_finished();
+                    return Some((Ok(task), state));
+                }
+
+                tokio::time::sleep(self.poll_interval).await;
+            }
+        })
+    }
+
+    /// Wait for the task to finish
+    ///
+    /// Note that this resolves once the task has finished, no matter if it succeeded or not.
+    /// Check [`Task::status`] of the returned value.
+    pub async fn wait(&self) -> Result<Task, TaskError> {
+        loop {
+            let task = self.get().await?;
+            if task.is_finished() {
+                return Ok(task);
+            }
+
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+}
+
+/// Error while following a task
+#[derive(Debug)]
+pub enum TaskError {
+    /// The task no longer exists on the server, it may have been destroyed
+    /// by its creator once finished
+    Vanished,
+    MultipleMatches,
+    Rpc(RpcError),
+}
@@ -0,0 +1,28 @@
+use super::{Task, TaskStatus};
+
+#[test]
+fn pending() {
+    let s = include_str!("../../../test_data/task/vm_copy.json");
+    let task: Task = serde_json::from_str(s).unwrap();
+
+    assert_eq!(task.id.0, "deadbeaf-dead-beaf-dead-beafdeadbea2");
+    assert_eq!(task.name_label, "Async.VM.copy");
+    assert_eq!(task.status, TaskStatus::Pending);
+    assert_eq!(task.progress, 0.42);
+    assert_eq!(task.created, Some(1623464711));
+    assert_eq!(task.finished, None);
+    assert!(task.error_info.is_empty());
+    assert!(!task.is_finished());
+    assert_eq!(task.host, "deadbeaf-dead-beaf-dead-beafdeadbeaf");
+}
+
+#[test]
+fn failed() {
+    let s = include_str!("../../../test_data/task/sr_scan_failed.json");
+    let task: Task = serde_json::from_str(s).unwrap();
+
+    assert_eq!(task.status, TaskStatus::Failure);
+    assert!(task.is_finished());
+    assert_eq!(task.finished, Some(1623464715));
+    assert_eq!(task.error_info[0], "SR_BACKEND_FAILURE_40");
+}
@@ -0,0 +1,62 @@
+use crate::{declare_id_type, impl_xo_object};
+
+declare_id_type! {
+    /// Unique id of a task
+    pub struct TaskId;
+}
+
+/// Type representing a XAPI task
+///
+/// Tasks are created by long running operations like copying or migrating VMs, scanning
+/// SRs and running backups.
+///
+/// Also see https://github.com/vatesfr/xen-orchestra/blob/a505cd9567233aab7ca6488b2fb8a0b6c610fa08/packages/xo-server/src/xapi-object-to-xo.mjs
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct Task {
+    pub id: TaskId,
+    pub name_label: String,
+    pub name_description: String,
+
+    /// Progress of the task, from 0.0 to 1.0
+    pub progress: f64,
+    pub status: TaskStatus,
+
+    /// Result of the task once it has succeeded, usually a reference to an object
+    #[serde(default)]
+    pub result: String,
+
+    /// Error code followed by its parameters if the task has failed
+    #[serde(default)]
+    pub error_info: Vec<String>,
+
+    /// Unix timestamp of when the task was created
+    #[serde(default)]
+    pub created: Option<u64>,
+
+    /// Unix timestamp of when the task finished
+    #[serde(default)]
+    pub finished: Option<u64>,
+
+    /// The host the task is running on
+    #[serde(rename = "$host")]
+    pub host: String,
+}
+impl_xo_object!(Task => Task, TaskId);
+
+impl Task {
+    /// Check if the task has finished, no matter if it succeeded or not
+    pub fn is_finished(&self) -> bool {
+        !matches!(self.status, TaskStatus::Pending | TaskStatus::Cancelling)
+    }
+}
+
+/// Type describing status of a task
+#[derive(Debug, Clone, Copy, serde::Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskStatus {
+    Pending,
+    Success,
+    Failure,
+    Cancelling,
+    Cancelled,
+}
@@ -1,20 +1,56 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
 
-use jsonrpsee_types::{traits::Client, v2::params::ParamsSer};
+use jsonrpsee_types::{traits::Client, v2::params::ParamsSer, JsonValue};
 use jsonrpsee_ws_client::WsClient;
 
-use crate::{credentials::Token, RpcError};
+use crate::{credentials::Token, procedure_args, RpcError};
 
 pub struct TokenProcedures {
     pub(crate) inner: Arc<WsClient>,
 }
 
+/// How long a token is valid for, see [`TokenProcedures::create_with_expiry`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenExpiry {
+    Duration(Duration),
+
+    /// Duration in the format understood by xo-server, like `30 days` or `12h`
+    Text(String),
+}
+
+impl From<Duration> for TokenExpiry {
+    fn from(duration: Duration) -> Self {
+        TokenExpiry::Duration(duration)
+    }
+}
+
+impl From<&str> for TokenExpiry {
+    fn from(text: &str) -> Self {
+        TokenExpiry::Text(text.to_string())
+    }
+}
+
+impl From<String> for TokenExpiry {
+    fn from(text: String) -> Self {
+        TokenExpiry::Text(text)
+    }
+}
+
+impl From<TokenExpiry> for JsonValue {
+    fn from(expiry: TokenExpiry) -> Self {
+        match expiry {
+            // xo-server takes numbers as milliseconds
+            TokenExpiry::Duration(duration) => (duration.as_millis() as u64).into(),
+            TokenExpiry::Text(text) => text.into(),
+        }
+    }
+}
+
 impl TokenProcedures {
-    /// Create authentication token
+    /// Create authentication token, the token uses the default expiry of xo-server
     ///
     /// xo-cli: token.create [expiresIn=<number|string>]
     pub async fn create(&self) -> Result<Token, RpcError> {
-        // TODO: consider specifying the `expiresIn` parameter
         let token: Token = self
             .inner
             .request("token.create", Some(ParamsSer::Map(BTreeMap::new())))
@@ -22,4 +58,63 @@ impl TokenProcedures {
 
         Ok(token)
     }
+
+    /// Create authentication token which expires after the specified duration
+    ///
+    /// Example
+    /// ```no_run
+    /// # async fn example(con: xo_api_client::Client) {
+    /// use std::time::Duration;
+    ///
+    /// let token = con
+    ///     .token
+    ///     .create_with_expiry(Duration::from_secs(3600))
+    ///     .await
+    ///     .expect("Failed to create token");
+    /// let token = con.token.create_with_expiry("30 days").await.unwrap();
+    /// # }
+    /// ```
+    ///
+    /// xo-cli: token.create [expiresIn=<number|string>]
+    pub async fn create_with_expiry(
+        &self,
+        expires_in: impl Into<TokenExpiry>,
+    ) -> Result<Token, RpcError> {
+        let params = procedure_args! { "expiresIn" => expires_in.into() };
+
+        self.inner
+            .request("token.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Delete authentication token, revoking it
+    ///
+    /// xo-cli: token.delete token=<string>
+    pub async fn delete(&self, token: &Token) -> Result<(), RpcError> {
+        let params = procedure_args! { "token" => token.0.expose() };
+
+        self.inner
+            .request::<JsonValue>("token.delete", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Delete all authentication tokens of the signed in user
+    ///
+    /// `except`: Token to keep, usually the one the client signed in with
+    ///
+    /// xo-cli: token.deleteAll [except=<string>]
+    pub async fn delete_all(&self, except: Option<&Token>) -> Result<(), RpcError> {
+        let mut params = procedure_args! {};
+        if let Some(except) = except {
+            params.insert("except", except.0.expose().into());
+        }
+
+        self.inner
+            .request::<JsonValue>("token.deleteAll", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
 }
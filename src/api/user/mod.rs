@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests;
+
+mod types;
+pub use types::{User, UserChanges, UserId, UserPermission};
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::{credentials::Secret, procedure_args, struct_to_map, RpcError};
+
+pub struct UserProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl UserProcedures {
+    /// Create a new user, returns the id of the created user
+    ///
+    /// `permission`: Global permission of the user, defaults to [`UserPermission::None`]
+    ///
+    /// xo-cli: user.create email=<string> password=<string> [permission=<string>]
+    pub async fn create(
+        &self,
+        email: String,
+        password: impl Into<Secret>,
+        permission: impl Into<Option<UserPermission>>,
+    ) -> Result<UserId, RpcError> {
+        let password = password.into();
+        let mut params = procedure_args! { "email" => email, "password" => password.expose() };
+        if let Some(permission) = permission.into() {
+            params.insert("permission", permission.into());
+        }
+
+        self.inner
+            .request("user.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get all users
+    ///
+    /// xo-cli: user.getAll
+    pub async fn get_all(&self) -> Result<Vec<User>, RpcError> {
+        self.inner
+            .request("user.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Change settings of a user
+    ///
+    /// xo-cli: user.set id=<string> [email=<string>] [password=<string>] [permission=<string>] [preferences=<object>]
+    pub async fn set(&self, user_id: UserId, changes: UserChanges) -> Result<(), RpcError> {
+        struct_to_map!(let params = changes);
+        let mut params = params;
+        params.insert("id", user_id.into());
+
+        self.inner
+            .request::<bool>("user.set", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Delete a user
+    ///
+    /// Note that a user can not delete themselves
+    ///
+    /// xo-cli: user.delete id=<string>
+    pub async fn delete(&self, user_id: UserId) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => user_id };
+
+        self.inner
+            .request::<bool>("user.delete", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Change the password of the signed in user
+    ///
+    /// xo-cli: user.changePassword oldPassword=<string> newPassword=<string>
+    pub async fn change_password(
+        &self,
+        old_password: impl Into<Secret>,
+        new_password: impl Into<Secret>,
+    ) -> Result<(), RpcError> {
+        let (old_password, new_password) = (old_password.into(), new_password.into());
+        let params = procedure_args! {
+            "oldPassword" => old_password.expose(),
+            "newPassword" => new_password.expose(),
+        };
+
+        self.inner
+            .request::<bool>("user.changePassword", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+}
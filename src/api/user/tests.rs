@@ -0,0 +1,50 @@
+use super::{User, UserChanges, UserPermission};
+
+#[test]
+fn user() {
+    let s = include_str!("../../../test_data/user/user.json");
+    let user: User = serde_json::from_str(s).unwrap();
+
+    assert_eq!(user.email, "jane.doe@example.com");
+    assert_eq!(user.permission, UserPermission::None);
+    assert!(!user.is_admin());
+    assert_eq!(user.groups.len(), 1);
+    assert!(user.preferences.contains_key("sshKeys"));
+    assert_eq!(
+        user.auth_providers["ldap"],
+        "uid=jane,ou=people,dc=example,dc=com"
+    );
+
+    // Users created by older versions of XO lack most fields
+    let user: User =
+        serde_json::from_str(r#"{ "id": "b", "email": "admin@admin.net", "permission": "admin" }"#)
+            .unwrap();
+    assert!(user.is_admin());
+    assert!(user.groups.is_empty());
+}
+
+#[test]
+fn user_changes() {
+    let changes = UserChanges {
+        permission: Some(UserPermission::Read),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(changes).unwrap(),
+        serde_json::json!({ "permission": "read" })
+    );
+}
+
+#[test]
+fn user_changes_password() {
+    let changes = UserChanges {
+        password: Some("hunter2".into()),
+        ..Default::default()
+    };
+
+    assert!(!format!("{:?}", changes).contains("hunter2"));
+    assert_eq!(
+        serde_json::to_value(changes).unwrap(),
+        serde_json::json!({ "password": "hunter2" })
+    );
+}
@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+
+use jsonrpsee_types::JsonValue;
+
+use crate::{api::group::GroupId, credentials::Secret, declare_id_type};
+
+declare_id_type! {
+    /// Unique id of a user
+    pub struct UserId;
+}
+
+/// Type representing a user of XO
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    pub id: UserId,
+    pub email: String,
+    pub permission: UserPermission,
+
+    /// Groups the user is a member of
+    #[serde(default)]
+    pub groups: Vec<GroupId>,
+
+    /// Preferences of the user in xo-web, like SSH keys
+    #[serde(default)]
+    pub preferences: BTreeMap<String, JsonValue>,
+
+    /// External authentication providers the user signs in with, like `ldap`, along
+    /// with the identity of the user for each provider
+    #[serde(default)]
+    pub auth_providers: BTreeMap<String, JsonValue>,
+}
+
+impl User {
+    pub fn is_admin(&self) -> bool {
+        self.permission == UserPermission::Admin
+    }
+}
+
+/// Global permission level of a user
+///
+/// Users without the admin permission only have access to objects granted through ACLs
+/// and resource sets.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum UserPermission {
+    #[default]
+    None,
+    Read,
+    Write,
+    Admin,
+}
+
+impl From<UserPermission> for JsonValue {
+    fn from(permission: UserPermission) -> Self {
+        serde_json::to_value(permission).unwrap()
+    }
+}
+
+/// Changes to apply to a user, unset fields are left unchanged
+///
+/// The password is a [`Secret`], so it is redacted in `Debug` output.
+#[derive(serde::Serialize, Debug, Clone, Default)]
+pub struct UserChanges {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<Secret>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission: Option<UserPermission>,
+
+    /// Preferences to set, other preferences are left unchanged
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferences: Option<BTreeMap<String, JsonValue>>,
+}
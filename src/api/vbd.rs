@@ -0,0 +1,6 @@
+use crate::declare_id_type;
+
+declare_id_type! {
+    /// Unique id of a virtual block device, which connects a VDI to a VM
+    pub struct VbdId;
+}
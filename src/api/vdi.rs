@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+
+use crate::{
+    api::{resource_set::ResourceSetId, sr::SrId},
+    declare_id_type, procedure_args, RpcError,
+};
+
+declare_id_type! {
+    /// Unique id of a virtual disk image
+    pub struct VdiId;
+}
+
+pub struct VdiProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl VdiProcedures {
+    /// Move a VDI to another SR
+    ///
+    /// `resource_set`: Resource set through which the target SR is used, required for
+    /// users who are not admins
+    ///
+    /// xo-cli: vdi.migrate id=<string> [resourceSet=<string>] sr_id=<string>
+    pub async fn migrate(
+        &self,
+        vdi_id: VdiId,
+        sr_id: SrId,
+        resource_set: impl Into<Option<ResourceSetId>>,
+    ) -> Result<(), RpcError> {
+        let mut params = procedure_args! { "id" => vdi_id, "sr_id" => sr_id };
+        if let Some(resource_set) = resource_set.into() {
+            params.insert("resourceSet", resource_set.into());
+        }
+
+        self.inner
+            .request::<JsonValue>("vdi.migrate", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::BTreeMap;
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, JsonValue};
+
+use crate::{
+    api::{
+        pool::PoolId,
+        remote::RemoteId,
+        sr::SrId,
+        vdi::VdiId,
+        vm::{VmId, VmProcedures},
+    },
+    procedure_args, RpcError,
+};
+
+/// Where [`VmProcedures::rolling_dr_copy`] copies a VM to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DrCopyTarget {
+    /// The default SR of the pool
+    Pool(PoolId),
+    Sr(SrId),
+}
+
+/// Result of [`VmProcedures::delta_copy`]
+#[derive(serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeltaCopyResult {
+    /// Id of the copy
+    #[serde(rename = "id")]
+    pub vm_id: VmId,
+
+    /// Number of bytes transferred
+    #[serde(default)]
+    pub transfer_size: Option<u64>,
+
+    /// Time the transfer took in milliseconds
+    #[serde(default)]
+    pub transfer_duration: Option<u64>,
+}
+
+/// Operations of the legacy backup system, which predates backup jobs
+///
+/// Each operation creates a backup named after `tag` and then removes the oldest backups
+/// with the same tag, keeping at most `retention` of them. New setups should use
+/// [`crate::api::backup_ng::BackupNgProcedures`] instead.
+///
+/// The deprecated `depth` parameter is not exposed, it is an alias of `retention`.
+impl VmProcedures {
+    /// Snapshot a VM with a tagged name, and remove the oldest snapshots with the same tag
+    ///
+    /// xo-cli: vm.rollingSnapshot id=<string> tag=<string> [retention=<number>] [depth=<number>]
+    pub async fn rolling_snapshot(
+        &self,
+        vm_id: VmId,
+        tag: String,
+        retention: u32,
+    ) -> Result<(), RpcError> {
+        let params = procedure_args! { "id" => vm_id, "tag" => tag, "retention" => retention };
+
+        self.inner
+            .request::<JsonValue>("vm.rollingSnapshot", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Export a VM to a remote with a tagged name, and remove the oldest exports with the
+    /// same tag
+    ///
+    /// xo-cli: vm.rollingBackup id=<string> remoteId=<string> tag=<string> [retention=<number>] [depth=<number>] [compress=<boolean>]
+    pub async fn rolling_backup(
+        &self,
+        vm_id: VmId,
+        remote_id: RemoteId,
+        tag: String,
+        retention: u32,
+        compress: bool,
+    ) -> Result<(), RpcError> {
+        let params = procedure_args! {
+            "id" => vm_id,
+            "remoteId" => remote_id,
+            "tag" => tag,
+            "retention" => retention,
+            "compress" => compress,
+        };
+
+        self.inner
+            .request::<JsonValue>("vm.rollingBackup", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Export the changes of a VM since its previous delta backup to a remote, and remove
+    /// the oldest delta backups with the same tag
+    ///
+    /// Returns the path of the backup on the remote, see [`Self::import_delta_backup`]
+    ///
+    /// xo-cli: vm.rollingDeltaBackup id=<string> remote=<string> tag=<string> [retention=<string|number>] [depth=<string|number>]
+    pub async fn rolling_delta_backup(
+        &self,
+        vm_id: VmId,
+        remote_id: RemoteId,
+        tag: String,
+        retention: u32,
+    ) -> Result<String, RpcError> {
+        let params = procedure_args! {
+            "id" => vm_id,
+            "remote" => remote_id,
+            "tag" => tag,
+            "retention" => retention,
+        };
+
+        self.inner
+            .request("vm.rollingDeltaBackup", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Copy a VM to another pool or SR with a tagged name, and remove the oldest copies with
+    /// the same tag
+    ///
+    /// `delete_old_backups_first`: Remove the oldest copies before copying, which needs
+    /// less space on the target but leaves fewer copies if the copy fails
+    ///
+    /// xo-cli: vm.rollingDrCopy [retention=<number>] [depth=<number>] id=<string> [pool=<string>] [sr=<string>] tag=<string> [deleteOldBackupsFirst=<boolean>]
+    pub async fn rolling_dr_copy(
+        &self,
+        vm_id: VmId,
+        target: DrCopyTarget,
+        tag: String,
+        retention: u32,
+        delete_old_backups_first: bool,
+    ) -> Result<(), RpcError> {
+        let mut params = procedure_args! {
+            "id" => vm_id,
+            "tag" => tag,
+            "retention" => retention,
+            "deleteOldBackupsFirst" => delete_old_backups_first,
+        };
+        match target {
+            DrCopyTarget::Pool(pool_id) => params.insert("pool", pool_id.into()),
+            DrCopyTarget::Sr(sr_id) => params.insert("sr", sr_id.into()),
+        };
+
+        self.inner
+            .request::<JsonValue>("vm.rollingDrCopy", Some(ParamsSer::Map(params)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Copy the changes of a VM since its previous delta copy to an SR
+    ///
+    /// `retention`: Number of copies to keep on the SR, if unset the default of xo-server
+    /// is used
+    ///
+    /// xo-cli: vm.deltaCopy [force=<boolean>] id=<string> [retention=<number>] sr=<string>
+    pub async fn delta_copy(
+        &self,
+        vm_id: VmId,
+        sr_id: SrId,
+        retention: impl Into<Option<u32>>,
+    ) -> Result<DeltaCopyResult, RpcError> {
+        let mut params = procedure_args! { "id" => vm_id, "sr" => sr_id };
+        if let Some(retention) = retention.into() {
+            params.insert("retention", retention.into());
+        }
+
+        self.inner
+            .request("vm.deltaCopy", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Import a VM from a backup file on a remote, returns the id of the imported VM
+    ///
+    /// xo-cli: vm.importBackup remote=<string> file=<string> sr=<string>
+    pub async fn import_backup(
+        &self,
+        remote_id: RemoteId,
+        file: String,
+        sr_id: SrId,
+    ) -> Result<VmId, RpcError> {
+        let params = procedure_args! { "remote" => remote_id, "file" => file, "sr" => sr_id };
+
+        self.inner
+            .request("vm.importBackup", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Import a VM from a delta backup on a remote, returns the id of the imported VM
+    ///
+    /// `map_vdis_srs`: SRs to import some of the disks to instead of `sr_id`, indexed by the
+    /// id of the disk in the backup
+    ///
+    /// xo-cli: vm.importDeltaBackup sr=<string> remote=<string> filePath=<string> [mapVdisSrs=<object>]
+    pub async fn import_delta_backup(
+        &self,
+        remote_id: RemoteId,
+        file_path: String,
+        sr_id: SrId,
+        map_vdis_srs: BTreeMap<VdiId, SrId>,
+    ) -> Result<VmId, RpcError> {
+        let mut params = procedure_args! {
+            "remote" => remote_id,
+            "filePath" => file_path,
+            "sr" => sr_id,
+        };
+        if !map_vdis_srs.is_empty() {
+            params.insert("mapVdisSrs", serde_json::to_value(map_vdis_srs)?);
+        }
+
+        self.inner
+            .request("vm.importDeltaBackup", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
@@ -1,20 +1,53 @@
 #[cfg(test)]
 mod tests;
 
+mod legacy_backup;
+mod snapshot_policy;
 mod types;
-pub use types::{OtherInfo, PowerState, Snapshot, SnapshotId, Vm, VmId, VmOrSnapshotId};
-
-use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer};
+pub use legacy_backup::{DeltaCopyResult, DrCopyTarget};
+pub use snapshot_policy::{
+    KeepReason, PlannedSnapshot, SnapshotPolicy, SnapshotPrunePlan, SnapshotSelector,
+};
+pub use types::{
+    NewVm, OtherInfo, PowerState, Snapshot, SnapshotId, TemplateId, Vm, VmId, VmOrSnapshotId,
+};
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, JsonValue};
 use jsonrpsee_ws_client::WsClient;
-use std::sync::Arc;
-
-use crate::{procedure_args, RpcError};
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::{
+    api::{
+        stats::{Granularity, VmStats},
+        vbd::VbdId,
+        vdi::VdiId,
+        xo::XoProcedures,
+    },
+    impl_xo_object, procedure_args, procedure_object, struct_to_map, RpcError,
+};
 
 pub struct VmProcedures {
     pub(crate) inner: Arc<WsClient>,
 }
 
 impl VmProcedures {
+    fn xo(&self) -> XoProcedures {
+        XoProcedures {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+
+    /// Create a new VM from a template, returns the id of the created VM
+    ///
+    /// xo-cli: vm.create [affinityHost=<string>] [bootAfterCreate=<boolean>] [cloudConfig=<string>] [networkConfig=<string>] [coreOs=<boolean>] [clone=<boolean>] [coresPerSocket=<string|number>] [resourceSet=<string>] [installation=<object>] [vgpuType=<string>] [gpuGroup=<string>] name_label=<string> [name_description=<string>] [pv_args=<string>] [share=<boolean>] template=<string> [VIFs=<array>] [VDIs=<array>] [existingDisks=<object>] [hvmBootFirmware=<string>] [copyHostBiosStrings=<boolean>] *=<any>
+    pub async fn create(&self, vm: NewVm) -> Result<VmId, RpcError> {
+        struct_to_map!(let params = vm);
+
+        self.inner
+            .request("vm.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
     /// This function will try to initiate a soft restart of the VM
     /// The there is no guarantee that the VM has started once the returned
     /// future resolves
@@ -64,7 +97,6 @@ impl VmProcedures {
         self.inner
             .request("vm.snapshot", Some(ParamsSer::Map(params)))
             .await
-            .map_err(Into::into)
     }
 
     /// Roll back Vm to an earlier snapshot
@@ -112,6 +144,54 @@ impl VmProcedures {
 
         Ok(())
     }
+
+    /// Get all snapshots of a VM, oldest first
+    ///
+    /// xo-cli: xo.getAllObjects filter=<object>
+    pub async fn list_snapshots(&self, vm_id: VmId) -> Result<Vec<Snapshot>, RpcError> {
+        let filter = procedure_object!("$snapshot_of" => vm_id);
+
+        let snapshots: BTreeMap<SnapshotId, Snapshot> = self.xo().get_objects(filter, None).await?;
+
+        let mut snapshots: Vec<_> = snapshots.into_values().collect();
+        snapshots.sort_by_key(|snapshot| snapshot.snapshot_time);
+        Ok(snapshots)
+    }
+
+    /// Get the disks of a snapshot, CD drives are left out
+    ///
+    /// xo-cli: xo.getAllObjects filter=<object>
+    pub async fn snapshot_vdis(&self, snapshot: &Snapshot) -> Result<Vec<VdiId>, RpcError> {
+        #[derive(serde::Deserialize)]
+        struct Vbd {
+            #[serde(rename = "VDI")]
+            vdi: Option<VdiId>,
+            #[serde(default)]
+            is_cd_drive: bool,
+        }
+        impl_xo_object!(Vbd => Vbd, VbdId);
+
+        let filter = procedure_object!("VM" => snapshot.id.clone());
+
+        let vbds: BTreeMap<VbdId, Vbd> = self.xo().get_objects(filter, None).await?;
+
+        Ok(vbds
+            .into_values()
+            .filter(|vbd| !vbd.is_cd_drive)
+            .filter_map(|vbd| vbd.vdi)
+            .collect())
+    }
+
+    /// Get performance statistics of a VM
+    ///
+    /// xo-cli: vm.stats id=<string> [granularity=<string>]
+    pub async fn stats(&self, vm_id: VmId, granularity: Granularity) -> Result<VmStats, RpcError> {
+        let params = procedure_args! { "id" => vm_id, "granularity" => granularity };
+
+        self.inner
+            .request("vm.stats", Some(ParamsSer::Map(params)))
+            .await
+    }
 }
 
 /// Error during restart of VM
@@ -0,0 +1,245 @@
+use std::{cmp::Reverse, fmt};
+
+use crate::{
+    api::vm::{Snapshot, SnapshotId, VmId, VmProcedures},
+    date::civil_from_days,
+    RpcError,
+};
+
+/// Which snapshots of a VM a [`SnapshotPolicy`] applies to
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SnapshotSelector {
+    #[default]
+    All,
+
+    /// Snapshots with a name starting with the prefix
+    NamePrefix(String),
+
+    /// Snapshots with the tag
+    Tag(String),
+}
+
+impl SnapshotSelector {
+    pub fn matches(&self, snapshot: &Snapshot) -> bool {
+        match self {
+            SnapshotSelector::All => true,
+            SnapshotSelector::NamePrefix(prefix) => snapshot.name_label.starts_with(prefix),
+            SnapshotSelector::Tag(tag) => snapshot.tags.contains(tag),
+        }
+    }
+}
+
+/// Retention policy deciding which snapshots of a VM to keep
+///
+/// The snapshots selected by `selector` are kept if they are one of the `keep_last` most
+/// recent ones, or the most recent one of one of the `keep_daily` most recent days with
+/// a snapshot, and likewise for weeks and months. All other selected snapshots are to
+/// be deleted, snapshots not selected are left alone. Days, weeks (starting on Monday)
+/// and months are in UTC.
+///
+/// Note that a policy keeping nothing deletes all selected snapshots.
+///
+/// Example keeping the last 3 snapshots, and one per day for a week
+/// ```no_run
+/// # async fn example(con: xo_api_client::Client, vm_id: xo_api_client::api::vm::VmId) {
+/// use xo_api_client::api::vm::{SnapshotPolicy, SnapshotSelector};
+///
+/// let policy = SnapshotPolicy {
+///     selector: SnapshotSelector::Tag("nightly".to_string()),
+///     keep_last: 3,
+///     keep_daily: 7,
+///     ..Default::default()
+/// };
+///
+/// let snapshots = con
+///     .vm
+///     .list_snapshots(vm_id.clone())
+///     .await
+///     .expect("Failed to list snapshots");
+/// let plan = policy.plan(&vm_id, &snapshots);
+/// println!("{}", plan);
+///
+/// con.vm.prune_snapshots(&plan).await.expect("Failed to delete snapshots");
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SnapshotPolicy {
+    pub selector: SnapshotSelector,
+    pub keep_last: usize,
+    pub keep_daily: usize,
+    pub keep_weekly: usize,
+    pub keep_monthly: usize,
+}
+
+/// Why a snapshot is kept by a [`SnapshotPolicy`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum KeepReason {
+    Last,
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+/// Snapshot in a [`SnapshotPrunePlan`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedSnapshot {
+    pub id: SnapshotId,
+    pub name_label: String,
+    pub snapshot_time: u64,
+
+    /// Empty for snapshots to be deleted
+    pub reasons: Vec<KeepReason>,
+}
+
+/// Outcome of applying a [`SnapshotPolicy`] to the snapshots of a VM, most recent first
+///
+/// The plan doubles as a dry-run report through its `Display` implementation, and is
+/// carried out by [`VmProcedures::prune_snapshots`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotPrunePlan {
+    pub vm_id: VmId,
+    pub keep: Vec<PlannedSnapshot>,
+    pub delete: Vec<PlannedSnapshot>,
+}
+
+impl SnapshotPolicy {
+    /// Compute which snapshots of the VM to keep and which to delete
+    ///
+    /// `snapshots` may contain snapshots of other VMs, they are ignored.
+    pub fn plan<'a>(
+        &self,
+        vm_id: &VmId,
+        snapshots: impl IntoIterator<Item = &'a Snapshot>,
+    ) -> SnapshotPrunePlan {
+        let mut snapshots: Vec<PlannedSnapshot> = snapshots
+            .into_iter()
+            .filter(|snapshot| snapshot.snapshot_of.as_ref() == Some(vm_id))
+            .filter(|snapshot| self.selector.matches(snapshot))
+            .map(|snapshot| PlannedSnapshot {
+                id: snapshot.id.clone(),
+                name_label: snapshot.name_label.clone(),
+                snapshot_time: snapshot.snapshot_time,
+                reasons: Vec::new(),
+            })
+            .collect();
+        snapshots.sort_by(|a, b| (b.snapshot_time, &b.id).cmp(&(a.snapshot_time, &a.id)));
+
+        let day = |time: u64| time as i64 / 86400;
+        // 1970-01-01 is a Thursday, shift so that weeks start on Monday
+        let week = |time| (day(time) + 3).div_euclid(7);
+        let month = |time| {
+            let (year, month, _) = civil_from_days(day(time));
+            year * 12 + i64::from(month)
+        };
+
+        mark(&mut snapshots, KeepReason::Last, self.keep_last, |_| None);
+        mark(&mut snapshots, KeepReason::Daily, self.keep_daily, |t| {
+            Some(day(t))
+        });
+        mark(&mut snapshots, KeepReason::Weekly, self.keep_weekly, |t| {
+            Some(week(t))
+        });
+        mark(
+            &mut snapshots,
+            KeepReason::Monthly,
+            self.keep_monthly,
+            |t| Some(month(t)),
+        );
+
+        let (keep, delete) = snapshots
+            .into_iter()
+            .partition(|snapshot| !snapshot.reasons.is_empty());
+
+        SnapshotPrunePlan {
+            vm_id: vm_id.clone(),
+            keep,
+            delete,
+        }
+    }
+}
+
+/// Mark the most recent snapshot of each of the `count` most recent periods with
+/// `reason`, where `period` returns the period of a snapshot time. Periods of `None`
+/// mean every snapshot is its own period.
+fn mark(
+    snapshots: &mut [PlannedSnapshot],
+    reason: KeepReason,
+    count: usize,
+    period: impl Fn(u64) -> Option<i64>,
+) {
+    let mut kept = 0;
+    let mut last_period = None;
+    for snapshot in snapshots {
+        if kept >= count {
+            break;
+        }
+
+        let period = period(snapshot.snapshot_time);
+        if period.is_none() || period != last_period {
+            snapshot.reasons.push(reason);
+            kept += 1;
+            last_period = period;
+        }
+    }
+}
+
+impl fmt::Display for SnapshotPrunePlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "VM {}: keeping {}, deleting {} snapshot(s)",
+            self.vm_id.0,
+            self.keep.len(),
+            self.delete.len()
+        )?;
+
+        let mut all: Vec<_> = self.keep.iter().chain(&self.delete).collect();
+        all.sort_by_key(|snapshot| Reverse(snapshot.snapshot_time));
+        for snapshot in all {
+            let time = snapshot.snapshot_time as i64;
+            let (year, month, day) = civil_from_days(time.div_euclid(86400));
+            let seconds = time.rem_euclid(86400);
+            write!(
+                f,
+                "{} {:04}-{:02}-{:02} {:02}:{:02}:{:02} {} {:?}",
+                if snapshot.reasons.is_empty() {
+                    "delete"
+                } else {
+                    "keep  "
+                },
+                year,
+                month,
+                day,
+                seconds / 3600,
+                seconds / 60 % 60,
+                seconds % 60,
+                snapshot.id.0,
+                snapshot.name_label,
+            )?;
+            if !snapshot.reasons.is_empty() {
+                write!(f, " {:?}", snapshot.reasons)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl VmProcedures {
+    /// Delete the snapshots a [`SnapshotPrunePlan`] marks for deletion
+    ///
+    /// Stops at the first snapshot that fails to be deleted
+    pub async fn prune_snapshots(&self, plan: &SnapshotPrunePlan) -> Result<(), RpcError> {
+        for snapshot in &plan.delete {
+            log::debug!(
+                "prune_snapshots: deleting {:?} of {:?}",
+                snapshot.id,
+                plan.vm_id
+            );
+            self.delete(snapshot.id.clone()).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -3,14 +3,22 @@ fn snapshots() {
     use super::types::Snapshot;
 
     let s = include_str!("../../../test_data/snapshot/debian_10.json");
-    let debian_snapshot: Snapshot = serde_json::from_str(&s).unwrap();
+    let debian_snapshot: Snapshot = serde_json::from_str(s).unwrap();
 
     assert_eq!(debian_snapshot.id.0, "deadbeaf-dead-beaf-dead-beafdeadbea0");
     assert_eq!(debian_snapshot.name_label, "[XO My Backup Job] debian 10");
     assert_eq!(debian_snapshot.name_description, "");
+    assert_eq!(debian_snapshot.snapshot_time, 1623464711);
+    assert_eq!(
+        debian_snapshot.snapshot_of.as_ref().unwrap().0,
+        "deadbeaf-dead-beaf-dead-beafdeadbeaf"
+    );
+    assert_eq!(debian_snapshot.tags, vec!["Test"]);
+    assert_eq!(debian_snapshot.vbds.len(), 1);
+    assert!(!debian_snapshot.includes_memory());
 
     let s = include_str!("../../../test_data/snapshot/pfsense_2_5_1.json");
-    let pfsense_snapshot: Snapshot = serde_json::from_str(&s).unwrap();
+    let pfsense_snapshot: Snapshot = serde_json::from_str(s).unwrap();
 
     assert_eq!(
         pfsense_snapshot.id.0,
@@ -21,14 +29,24 @@ fn snapshots() {
         "[XO My Backup Job] pfsense 2.5.1"
     );
     assert_eq!(pfsense_snapshot.name_description, "Foo description");
+
+    let s = include_str!("../../../test_data/snapshot/with_memory.json");
+    let memory_snapshot: Snapshot = serde_json::from_str(s).unwrap();
+
+    assert!(memory_snapshot.includes_memory());
+    assert_eq!(
+        memory_snapshot.parent.unwrap().0,
+        "deadbeaf-dead-beaf-dead-beafdeadbea0"
+    );
+    assert_eq!(memory_snapshot.vbds.len(), 2);
 }
 
 macro_rules! file_to_vm {
     ($path:literal) => {{
         let s = include_str!($path);
 
-        let hash_vm: super::Vm<HashMap<String, String>> = serde_json::from_str(&s).unwrap();
-        let tree_vm: super::Vm<BTreeMap<String, String>> = serde_json::from_str(&s).unwrap();
+        let hash_vm: super::Vm<HashMap<String, String>> = serde_json::from_str(s).unwrap();
+        let tree_vm: super::Vm<BTreeMap<String, String>> = serde_json::from_str(s).unwrap();
         (hash_vm, tree_vm)
     }};
 }
@@ -74,6 +92,11 @@ fn debian() {
     );
     assert_eq!(debian.distro().unwrap(), "debian");
     assert_eq!(debian.pool, "deadbeaf-dead-beaf-dead-beafdeadbeaf");
+    assert_eq!(debian.snapshots.len(), 4);
+    assert_eq!(
+        debian.snapshots[0].0,
+        "deadbeaf-dead-beaf-dead-beafdeadbea0"
+    );
 }
 
 #[test]
@@ -187,7 +210,7 @@ fn windows() {
     );
     assert_eq!(
         windows.ipv4_addresses().collect::<Vec<_>>(),
-        vec![
+        [
             "192.168.7.42",
             "192.168.8.42",
             "192.168.9.42",
@@ -201,7 +224,7 @@ fn windows() {
     assert_eq!(windows.power_state, PowerState::Running);
     assert_eq!(windows.name_label, "windows 10");
     assert_eq!(windows.name_description, "Here is a description");
-    assert_eq!(windows.tags, [String::new(); 0]);
+    assert!(windows.tags.is_empty());
     assert_eq!(
         windows.os_version,
         slice_to_map(&[("spmajor", "0"), ("spminor", "0")])
@@ -282,3 +305,102 @@ fn slice_to_map<T: FromIterator<(String, String)>>(slice: &[(&str, &str)]) -> T
         .map(|(key, value)| (key.to_string(), value.to_string()))
         .collect()
 }
+
+#[test]
+fn delta_copy_result() {
+    let s = r#"{ "id": "deadbeaf-dead-beaf-dead-beafdeadbea2", "transferSize": 1073741824, "transferDuration": 52311 }"#;
+    let result: super::DeltaCopyResult = serde_json::from_str(s).unwrap();
+
+    assert_eq!(result.vm_id.0, "deadbeaf-dead-beaf-dead-beafdeadbea2");
+    assert_eq!(result.transfer_size, Some(1073741824));
+    assert_eq!(result.transfer_duration, Some(52311));
+}
+
+#[test]
+fn snapshot_policy() {
+    use super::{KeepReason, SnapshotPolicy, SnapshotSelector, VmId};
+
+    // 40 nightly snapshots, one per day from 2021-01-01T03:00:00Z (a Friday), along with
+    // an untagged snapshot and a snapshot of another VM
+    let s = include_str!("../../../test_data/snapshot/policy.json");
+    let snapshots: Vec<super::Snapshot> = serde_json::from_str(s).unwrap();
+
+    let policy = SnapshotPolicy {
+        selector: SnapshotSelector::Tag("nightly".to_string()),
+        keep_last: 2,
+        keep_daily: 5,
+        keep_weekly: 3,
+        keep_monthly: 2,
+    };
+    let plan = policy.plan(&VmId("vm".to_string()), &snapshots);
+
+    let kept: Vec<_> = plan
+        .keep
+        .iter()
+        .map(|snapshot| (snapshot.id.0.as_str(), snapshot.reasons.clone()))
+        .collect();
+    assert_eq!(
+        kept,
+        [
+            (
+                "nightly-39",
+                vec![
+                    KeepReason::Last,
+                    KeepReason::Daily,
+                    KeepReason::Weekly,
+                    KeepReason::Monthly
+                ]
+            ),
+            ("nightly-38", vec![KeepReason::Last, KeepReason::Daily]),
+            ("nightly-37", vec![KeepReason::Daily, KeepReason::Weekly]),
+            ("nightly-36", vec![KeepReason::Daily]),
+            ("nightly-35", vec![KeepReason::Daily]),
+            ("nightly-30", vec![KeepReason::Weekly, KeepReason::Monthly]),
+        ]
+    );
+
+    // Neither the untagged snapshot nor the snapshot of another VM are touched
+    assert_eq!(plan.delete.len(), 34);
+    assert!(plan
+        .delete
+        .iter()
+        .all(|snapshot| snapshot.id.0.starts_with("nightly-")));
+
+    let report = plan.to_string();
+    assert!(report.starts_with("VM vm: keeping 6, deleting 34 snapshot(s)\n"));
+    assert!(report.contains("keep   2021-02-09 03:00:00 nightly-39"));
+    assert!(report.contains("delete 2021-01-01 03:00:00 nightly-0 "));
+
+    // A policy keeping nothing deletes every selected snapshot
+    let plan = SnapshotPolicy {
+        selector: SnapshotSelector::NamePrefix("snapshot manual".to_string()),
+        ..Default::default()
+    }
+    .plan(&VmId("vm".to_string()), &snapshots);
+    assert!(plan.keep.is_empty());
+    assert_eq!(plan.delete.len(), 1);
+}
+
+#[test]
+fn new_vm() {
+    use super::{NewVm, TemplateId};
+    use crate::api::resource_set::ResourceSetId;
+
+    let mut vm = NewVm::new(
+        "dev box".to_string(),
+        TemplateId("deadbeaf-dead-beaf-dead-beafdeadbeb4".to_string()),
+    );
+    vm.resource_set = Some(ResourceSetId("Hk7Qz3aLx".to_string()));
+    vm.other
+        .insert("VIFs".to_string(), serde_json::json!([{ "network": "n" }]));
+
+    assert_eq!(
+        serde_json::to_value(vm).unwrap(),
+        serde_json::json!({
+            "name_label": "dev box",
+            "template": "deadbeaf-dead-beaf-dead-beafdeadbeb4",
+            "resourceSet": "Hk7Qz3aLx",
+            "VIFs": [{ "network": "n" }],
+        })
+    );
+}
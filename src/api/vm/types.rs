@@ -2,7 +2,12 @@ use std::{collections::BTreeMap, net::Ipv4Addr};
 
 use jsonrpsee_types::{DeserializeOwned, JsonValue};
 
-use crate::{declare_id_type, impl_xo_object, types::XoObject};
+use crate::{
+    api::{host::HostId, resource_set::ResourceSetId, vbd::VbdId, vdi::VdiId},
+    declare_id_type, impl_xo_object,
+    types::XoObject,
+    ObjectType,
+};
 
 /// Type representing a VM
 ///
@@ -28,11 +33,15 @@ pub struct Vm<O> {
     #[serde(deserialize_with = "map_from_optional_map", default)]
     pub os_version: BTreeMap<String, String>,
 
+    /// Snapshots of the VM, see [`crate::api::vm::VmProcedures::list_snapshots`]
+    #[serde(default)]
+    pub snapshots: Vec<SnapshotId>,
+
     pub other: O,
 }
 
 impl<O: DeserializeOwned> XoObject for Vm<O> {
-    const OBJECT_TYPE: &'static str = "VM";
+    const OBJECT_TYPE: ObjectType = ObjectType::Vm;
     type IdType = VmId;
 }
 
@@ -45,7 +54,7 @@ pub enum PowerState {
     Paused,
 }
 
-impl<'a, O: serde::de::DeserializeOwned> Vm<O> {
+impl<O: serde::de::DeserializeOwned> Vm<O> {
     /// Check if VM is running.
     pub fn is_running(&self) -> bool {
         matches!(self.power_state, PowerState::Running)
@@ -112,6 +121,58 @@ declare_id_type! {
     pub struct SnapshotId;
 }
 
+declare_id_type! {
+    /// Unique id of a VM template
+    pub struct TemplateId;
+}
+
+/// Parameters for a new VM, see [`crate::api::vm::VmProcedures::create`]
+///
+/// Parameters not modelled by this type, like `VIFs`, `VDIs` or `cloudConfig`, can be
+/// set in `other`.
+#[derive(serde::Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NewVm {
+    #[serde(rename = "name_label")]
+    pub name_label: String,
+    #[serde(rename = "name_description", skip_serializing_if = "Option::is_none")]
+    pub name_description: Option<String>,
+    pub template: TemplateId,
+
+    /// Resource set the VM is created through, required for users who are not admins
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_set: Option<ResourceSetId>,
+
+    /// Share the VM with the other subjects of `resource_set`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share: Option<bool>,
+
+    /// Host the VM should preferably run on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affinity_host: Option<HostId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boot_after_create: Option<bool>,
+
+    #[serde(flatten)]
+    pub other: BTreeMap<String, JsonValue>,
+}
+
+impl NewVm {
+    pub fn new(name_label: String, template: TemplateId) -> Self {
+        NewVm {
+            name_label,
+            name_description: None,
+            template,
+            resource_set: None,
+            share: None,
+            affinity_host: None,
+            boot_after_create: None,
+            other: BTreeMap::new(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
 pub struct VmOrSnapshotId(pub(crate) String);
 
@@ -139,5 +200,36 @@ pub struct Snapshot {
     pub id: SnapshotId,
     pub name_label: String,
     pub name_description: String,
+
+    /// Unix timestamp in seconds of when the snapshot was taken
+    pub snapshot_time: u64,
+
+    /// The VM this is a snapshot of, `None` if that VM no longer exists
+    #[serde(rename = "$snapshot_of", default)]
+    pub snapshot_of: Option<VmId>,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    /// The snapshot this snapshot was taken on top of, which makes up the snapshot tree
+    /// of the VM. `None` for the first snapshot of a VM.
+    #[serde(default)]
+    pub parent: Option<SnapshotId>,
+
+    /// VDI holding the memory of the VM, only for snapshots including memory
+    #[serde(rename = "suspendVdi", default)]
+    pub suspend_vdi: Option<VdiId>,
+
+    /// Block devices connecting the disks of the snapshot, see
+    /// [`crate::api::vm::VmProcedures::snapshot_vdis`]
+    #[serde(rename = "$VBDs", default)]
+    pub vbds: Vec<VbdId>,
+}
+
+impl Snapshot {
+    /// Whether the memory of the VM was saved along with its disks
+    pub fn includes_memory(&self) -> bool {
+        self.suspend_vdi.is_some()
+    }
 }
-impl_xo_object!(Snapshot => "VM-snapshot", SnapshotId);
+impl_xo_object!(Snapshot => VmSnapshot, SnapshotId);
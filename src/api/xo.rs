@@ -20,6 +20,7 @@ impl XoProcedures {
     /// * `R` is a type that can hold that entire result set with all different types
     /// * `filter` is an optional filter
     /// * `limit` is an optional max limit on number of results
+    ///
     /// xo-cli: xo.getAllObjects [filter=<object>] [limit=<number>] [ndjson=<boolean>]
     pub async fn get_all_objects<R: serde::de::DeserializeOwned>(
         &self,
@@ -41,7 +42,8 @@ impl XoProcedures {
     }
 
     /// Get all objects of specified type from server
-    /// * `R` is a type that can represent that collection of objects
+    /// * `R` is a type that can represent that collection of objects, see
+    ///   [`crate::AnyObjectMap`] for a map able to hold objects of any type
     /// * `filter` is an optional filter
     /// * `limit` is an optional max limit on number of results
     pub async fn get_objects<R: XoObjectMap>(
@@ -49,8 +51,12 @@ impl XoProcedures {
         filter: impl Into<Option<serde_json::Map<String, JsonValue>>>,
         limit: impl Into<Option<usize>>,
     ) -> Result<R, RpcError> {
-        let mut filter = filter.into().unwrap_or_default();
-        filter.insert("type".to_string(), R::Object::OBJECT_TYPE.into());
+        let mut filter = filter.into();
+        if let Some(object_type) = R::OBJECT_TYPE {
+            filter
+                .get_or_insert_with(Default::default)
+                .insert("type".to_string(), object_type.into());
+        }
 
         self.get_all_objects(filter, limit).await
     }
@@ -67,7 +73,7 @@ impl XoProcedures {
     {
         let filter = procedure_object!(
             "id" => id.clone(),
-            "type" => R::OBJECT_TYPE.to_string()
+            "type" => R::OBJECT_TYPE
         );
 
         // TODO: Can we get rid of the BTreeMap here?
@@ -0,0 +1,228 @@
+use std::{collections::BTreeMap, env, fmt, fs, io, path::Path, path::PathBuf};
+
+use crate::credentials::{Credentials, EmailAndPassword, Token};
+
+/// Credentials along with the URL of the server they are for
+///
+/// This can be loaded from the environment, a credentials file or the config of xo-cli.
+///
+/// Example of signing in with whichever is available
+/// ```no_run
+/// # async fn example() {
+/// use xo_api_client::{credentials::ServerCredentials, Client};
+///
+/// let ServerCredentials { url, credentials } = ServerCredentials::from_env()
+///     .or_else(|_| ServerCredentials::from_xo_cli_config())
+///     .expect("No credentials found");
+///
+/// let con = Client::connect(&url).await.expect("Failed to connect");
+/// con.session.sign_in(credentials).await.expect("Failed to sign in");
+/// # }
+/// ```
+pub struct ServerCredentials {
+    /// Websocket URL of the API endpoint, as expected by [`crate::Client::connect`]
+    pub url: String,
+    pub credentials: Credentials,
+}
+
+/// Error while loading [`ServerCredentials`]
+#[derive(Debug)]
+pub enum LoadCredentialsError {
+    /// A required environment variable or field is missing
+    Missing(&'static str),
+
+    /// The requested profile is not in the credentials file
+    UnknownProfile(String),
+    Io(io::Error),
+    Json(serde_json::Error),
+    Toml(toml::de::Error),
+}
+
+impl fmt::Display for LoadCredentialsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadCredentialsError::Missing(name) => write!(f, "Missing {}", name),
+            LoadCredentialsError::UnknownProfile(profile) => {
+                write!(f, "No profile named {:?}", profile)
+            }
+            LoadCredentialsError::Io(e) => write!(f, "Failed to read credentials: {}", e),
+            LoadCredentialsError::Json(e) => write!(f, "Invalid credentials file: {}", e),
+            LoadCredentialsError::Toml(e) => write!(f, "Invalid credentials file: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for LoadCredentialsError {}
+
+/// Profile used by [`ServerCredentials::from_file`] when none is specified
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Entry of a credentials file, either `token` or `email` and `password` must be set
+#[derive(serde::Deserialize)]
+struct Profile {
+    url: String,
+    token: Option<String>,
+    email: Option<String>,
+    password: Option<String>,
+}
+
+/// Config written by `xo-cli --register`
+#[derive(serde::Deserialize)]
+struct XoCliConfig {
+    server: Option<String>,
+    token: Option<String>,
+}
+
+impl ServerCredentials {
+    /// Load credentials from the environment variables `XO_URL` and either `XO_TOKEN`, or
+    /// `XO_EMAIL` and `XO_PASSWORD`
+    ///
+    /// `XO_URL` may be either the websocket URL of the API endpoint, or the HTTP URL of
+    /// the server like xo-cli uses.
+    pub fn from_env() -> Result<Self, LoadCredentialsError> {
+        Self::from_vars(|name| env::var(name).ok())
+    }
+
+    pub(crate) fn from_vars(
+        var: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, LoadCredentialsError> {
+        let url = var("XO_URL").ok_or(LoadCredentialsError::Missing("XO_URL"))?;
+        let credentials = credentials(
+            var("XO_TOKEN"),
+            var("XO_EMAIL"),
+            var("XO_PASSWORD"),
+            "XO_TOKEN or XO_EMAIL and XO_PASSWORD",
+        )?;
+
+        Ok(ServerCredentials {
+            url: api_url(&url),
+            credentials,
+        })
+    }
+
+    /// Load credentials from a file with one entry per profile
+    ///
+    /// Files with the `.json` extension are parsed as JSON, others as TOML. `profile`
+    /// defaults to [`DEFAULT_PROFILE`].
+    ///
+    /// Example of a TOML credentials file
+    /// ```toml
+    /// [default]
+    /// url = "wss://xo.example.com/api/"
+    /// token = "kZ8cLrOFp2xVqbT0Yf3m"
+    ///
+    /// [lab]
+    /// url = "ws://10.0.0.5/api/"
+    /// email = "admin@admin.net"
+    /// password = "admin"
+    /// ```
+    pub fn from_file(
+        path: impl AsRef<Path>,
+        profile: Option<&str>,
+    ) -> Result<Self, LoadCredentialsError> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(LoadCredentialsError::Io)?;
+
+        let is_json = path
+            .extension()
+            .is_some_and(|extension| extension == "json");
+        Self::from_file_content(&content, is_json, profile)
+    }
+
+    pub(crate) fn from_file_content(
+        content: &str,
+        is_json: bool,
+        profile: Option<&str>,
+    ) -> Result<Self, LoadCredentialsError> {
+        let mut profiles: BTreeMap<String, Profile> = if is_json {
+            serde_json::from_str(content).map_err(LoadCredentialsError::Json)?
+        } else {
+            toml::from_str(content).map_err(LoadCredentialsError::Toml)?
+        };
+
+        let name = profile.unwrap_or(DEFAULT_PROFILE);
+        let profile = profiles
+            .remove(name)
+            .ok_or_else(|| LoadCredentialsError::UnknownProfile(name.to_string()))?;
+        let credentials = credentials(
+            profile.token,
+            profile.email,
+            profile.password,
+            "token or email and password",
+        )?;
+
+        Ok(ServerCredentials {
+            url: api_url(&profile.url),
+            credentials,
+        })
+    }
+
+    /// Load the server and token saved by `xo-cli --register`
+    ///
+    /// The config is read from `$XDG_CONFIG_HOME/xo-cli/config.json`, which defaults to
+    /// `~/.config/xo-cli/config.json`.
+    pub fn from_xo_cli_config() -> Result<Self, LoadCredentialsError> {
+        let config_dir = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+            .ok_or(LoadCredentialsError::Missing("HOME"))?;
+
+        let path = config_dir.join("xo-cli").join("config.json");
+        let content = fs::read_to_string(path).map_err(LoadCredentialsError::Io)?;
+        Self::from_xo_cli_config_content(&content)
+    }
+
+    pub(crate) fn from_xo_cli_config_content(content: &str) -> Result<Self, LoadCredentialsError> {
+        let config: XoCliConfig =
+            serde_json::from_str(content).map_err(LoadCredentialsError::Json)?;
+
+        let url = config
+            .server
+            .ok_or(LoadCredentialsError::Missing("server"))?;
+        let token = config.token.ok_or(LoadCredentialsError::Missing("token"))?;
+
+        Ok(ServerCredentials {
+            url: api_url(&url),
+            credentials: Token::from(token).into(),
+        })
+    }
+}
+
+/// Pick token if set, otherwise email and password
+fn credentials(
+    token: Option<String>,
+    email: Option<String>,
+    password: Option<String>,
+    missing: &'static str,
+) -> Result<Credentials, LoadCredentialsError> {
+    match (token, email, password) {
+        (Some(token), _, _) => Ok(Token::from(token).into()),
+        (None, Some(email), Some(password)) => Ok(EmailAndPassword {
+            email,
+            password: password.into(),
+        }
+        .into()),
+        _ => Err(LoadCredentialsError::Missing(missing)),
+    }
+}
+
+/// Turn the HTTP URL of a server, like the one xo-cli saves, into the websocket URL of
+/// its API endpoint. `api/` is appended unless the path already ends with it, other paths
+/// being the prefix of a reverse proxy.
+pub(crate) fn api_url(url: &str) -> String {
+    let (scheme, rest) = match url.split_once("://") {
+        Some(("https", rest)) => ("wss", rest),
+        Some(("http", rest)) => ("ws", rest),
+        Some((scheme, rest)) => (scheme, rest),
+        // xo-cli accepts bare host names
+        None => ("ws", url),
+    };
+
+    let rest = rest.trim_end_matches('/');
+    if rest.ends_with("/api") {
+        format!("{}://{}/", scheme, rest)
+    } else {
+        // The path is the prefix of a reverse proxy, like `/xo`
+        format!("{}://{}/api/", scheme, rest)
+    }
+}
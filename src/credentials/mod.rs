@@ -1,22 +1,33 @@
-use std::{collections::BTreeMap, str::FromStr};
+#[cfg(test)]
+mod tests;
+
+mod load;
+mod secret;
+pub use load::{LoadCredentialsError, ServerCredentials, DEFAULT_PROFILE};
+pub use secret::Secret;
+
+use std::{collections::BTreeMap, fmt, str::FromStr};
 
 use crate::types::Impossible;
 use jsonrpsee_types::JsonValue;
 
 /// Login token used to authenticate with Xen Orchestra's API
+///
+/// The token is a [`Secret`], so it is redacted in `Debug` output. Use `Display` to get
+/// the actual token.
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
 #[serde(transparent)]
-pub struct Token(pub String);
+pub struct Token(pub Secret);
 
-impl ToString for Token {
-    fn to_string(&self) -> String {
-        self.0.clone()
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.expose())
     }
 }
 
 impl From<String> for Token {
     fn from(s: String) -> Self {
-        Token(s)
+        Token(s.into())
     }
 }
 
@@ -24,7 +35,7 @@ impl FromStr for Token {
     type Err = Impossible;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Token(s.to_string()))
+        Ok(Token(s.into()))
     }
 }
 
@@ -36,11 +47,13 @@ impl From<Token> for Credentials {
 
 /// Email and password used to authenticate with Xen Orchestra's API.
 ///
+/// The password is a [`Secret`], so it is redacted in `Debug` output.
+///
 /// Note that there is also the type [`Token`]
 #[derive(Debug, Clone)]
 pub struct EmailAndPassword {
     pub email: String,
-    pub password: String,
+    pub password: Secret,
 }
 
 impl From<EmailAndPassword> for Credentials {
@@ -52,20 +65,33 @@ impl From<EmailAndPassword> for Credentials {
 /// Some type of credentials used to authenticate with Xen Orchestra's API.
 ///
 /// A value of this type may ether contain a [`Token`] or an [`EmailAndPassword`]
+#[derive(Debug, Clone)]
 pub enum Credentials {
     Password(EmailAndPassword),
     Token(Token),
 }
 
-impl From<Credentials> for BTreeMap<&str, JsonValue> {
-    fn from(credentials: Credentials) -> Self {
-        use std::array::IntoIter;
-
+/// Parameters for signing in
+///
+/// The secrets are copied into plain `JsonValue`s, which are not zeroized on drop.
+impl From<&Credentials> for BTreeMap<&str, JsonValue> {
+    fn from(credentials: &Credentials) -> Self {
         match credentials {
-            Credentials::Password(EmailAndPassword { email, password }) => {
-                IntoIter::new([("email", email.into()), ("password", password.into())]).collect()
+            Credentials::Password(EmailAndPassword { email, password }) => [
+                ("email", email.as_str().into()),
+                ("password", password.expose().into()),
+            ]
+            .into_iter()
+            .collect(),
+            Credentials::Token(Token(token)) => {
+                [("token", token.expose().into())].into_iter().collect()
             }
-            Credentials::Token(Token(token)) => IntoIter::new([("token", token.into())]).collect(),
         }
     }
 }
+
+impl From<Credentials> for BTreeMap<&str, JsonValue> {
+    fn from(credentials: Credentials) -> Self {
+        (&credentials).into()
+    }
+}
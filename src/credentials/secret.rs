@@ -0,0 +1,57 @@
+use std::fmt;
+
+use zeroize::Zeroize;
+
+/// Secret string, like a password or token
+///
+/// The memory is zeroized on drop and `Debug` prints `***`, so secrets do not end up in
+/// logs. Use [`Secret::expose`] to access the actual value.
+///
+/// Secrets are still exposed wherever they are sent to xo-server: the `Display`
+/// implementations of [`Token`](super::Token) and
+/// [`RemoteUrl`](crate::api::remote::RemoteUrl), and the request parameters, which are
+/// plain `JsonValue`s that are not zeroized.
+///
+/// # Logging
+///
+/// `jsonrpsee-ws-client` logs every request, parameters included, at the `trace` level.
+/// Make sure the `jsonrpsee_ws_client` log target is filtered below `trace`, like with
+/// `RUST_LOG=trace,jsonrpsee_ws_client=debug` for `env_logger`, or secrets end up in logs.
+#[derive(Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Secret(secret.into())
+    }
+
+    /// Get the actual secret, be careful not to log it
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("***")
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl From<String> for Secret {
+    fn from(secret: String) -> Self {
+        Secret(secret)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(secret: &str) -> Self {
+        Secret(secret.to_string())
+    }
+}
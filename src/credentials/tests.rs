@@ -0,0 +1,153 @@
+use std::collections::BTreeMap;
+
+use jsonrpsee_types::JsonValue;
+
+use super::{
+    load::api_url, Credentials, EmailAndPassword, LoadCredentialsError, ServerCredentials, Token,
+};
+
+#[test]
+fn token_debug_is_redacted() {
+    let token = Token::from("kZ8cLrOFp2xVqbT0Yf3m".to_string());
+
+    assert_eq!(format!("{:?}", token), "Token(***)");
+    assert_eq!(format!("{:?}", Some(&token)), "Some(Token(***))");
+    assert_eq!(token.to_string(), "kZ8cLrOFp2xVqbT0Yf3m");
+}
+
+#[test]
+fn credentials_debug_is_redacted() {
+    let credentials = Credentials::from(EmailAndPassword {
+        email: "admin@admin.net".to_string(),
+        password: "hunter2".into(),
+    });
+
+    let debug = format!("{:?}", credentials);
+    assert!(!debug.contains("hunter2"), "{}", debug);
+    assert_eq!(
+        debug,
+        r#"Password(EmailAndPassword { email: "admin@admin.net", password: *** })"#
+    );
+
+    let debug = format!("{:?}", Credentials::from(Token::from("kZ8c".to_string())));
+    assert_eq!(debug, "Token(Token(***))");
+}
+
+#[test]
+fn credentials_params() {
+    let credentials = Credentials::from(EmailAndPassword {
+        email: "admin@admin.net".to_string(),
+        password: "hunter2".into(),
+    });
+    let params = BTreeMap::from(&credentials);
+    assert_eq!(params["email"], JsonValue::from("admin@admin.net"));
+    assert_eq!(params["password"], JsonValue::from("hunter2"));
+
+    let params = BTreeMap::from(Credentials::from(Token::from("kZ8c".to_string())));
+    assert_eq!(params["token"], JsonValue::from("kZ8c"));
+}
+
+fn assert_token(credentials: Credentials, expected: &str) {
+    match credentials {
+        Credentials::Token(token) => assert_eq!(token.to_string(), expected),
+        Credentials::Password(_) => panic!("Expected token"),
+    }
+}
+
+fn assert_password(credentials: Credentials, expected_email: &str, expected_password: &str) {
+    match credentials {
+        Credentials::Password(EmailAndPassword { email, password }) => {
+            assert_eq!(email, expected_email);
+            assert_eq!(password.expose(), expected_password);
+        }
+        Credentials::Token(_) => panic!("Expected email and password"),
+    }
+}
+
+#[test]
+fn from_vars() {
+    let vars: BTreeMap<_, _> = [
+        ("XO_URL", "https://xo.example.com/"),
+        ("XO_EMAIL", "admin@admin.net"),
+        ("XO_PASSWORD", "admin"),
+    ]
+    .into_iter()
+    .collect();
+
+    let creds = ServerCredentials::from_vars(|name| vars.get(name).map(|v| v.to_string()))
+        .expect("Failed to load credentials");
+    assert_eq!(creds.url, "wss://xo.example.com/api/");
+    assert_password(creds.credentials, "admin@admin.net", "admin");
+
+    let err = ServerCredentials::from_vars(|name| {
+        (name == "XO_URL").then(|| "ws://xo.example.com/api/".to_string())
+    })
+    .err()
+    .unwrap();
+    assert!(matches!(err, LoadCredentialsError::Missing(_)));
+}
+
+#[test]
+fn from_toml_file() {
+    let s = include_str!("../../test_data/credentials/credentials.toml");
+
+    let creds = ServerCredentials::from_file_content(s, false, None).unwrap();
+    assert_eq!(creds.url, "wss://xo.example.com/api/");
+    assert_token(creds.credentials, "kZ8cLrOFp2xVqbT0Yf3m");
+
+    let creds = ServerCredentials::from_file_content(s, false, Some("lab")).unwrap();
+    assert_eq!(creds.url, "ws://10.0.0.5/api/");
+    assert_password(creds.credentials, "admin@admin.net", "admin");
+
+    let err = ServerCredentials::from_file_content(s, false, Some("broken"))
+        .err()
+        .unwrap();
+    assert!(matches!(err, LoadCredentialsError::Missing(_)));
+
+    let err = ServerCredentials::from_file_content(s, false, Some("prod"))
+        .err()
+        .unwrap();
+    assert!(matches!(err, LoadCredentialsError::UnknownProfile(p) if p == "prod"));
+}
+
+#[test]
+fn from_json_file() {
+    let s = include_str!("../../test_data/credentials/credentials.json");
+
+    let creds = ServerCredentials::from_file_content(s, true, None).unwrap();
+    assert_eq!(creds.url, "wss://xo.example.com/api/");
+    assert_password(creds.credentials, "admin@admin.net", "admin");
+}
+
+#[test]
+fn from_xo_cli_config() {
+    let s = include_str!("../../test_data/credentials/xo-cli-config.json");
+
+    let creds = ServerCredentials::from_xo_cli_config_content(s).unwrap();
+    assert_eq!(creds.url, "wss://xo.example.com/api/");
+    assert_token(creds.credentials, "nP3qYvXeR8c0a1TbLm4z");
+}
+
+#[test]
+fn api_urls() {
+    assert_eq!(api_url("http://10.0.0.5"), "ws://10.0.0.5/api/");
+    assert_eq!(
+        api_url("https://xo.example.com/"),
+        "wss://xo.example.com/api/"
+    );
+    assert_eq!(api_url("xo.example.com"), "ws://xo.example.com/api/");
+    assert_eq!(
+        api_url("wss://xo.example.com/api/"),
+        "wss://xo.example.com/api/"
+    );
+    assert_eq!(
+        api_url("https://example.com/xo/api/"),
+        "wss://example.com/xo/api/"
+    );
+    assert_eq!(
+        api_url("https://example.com/xo/"),
+        "wss://example.com/xo/api/"
+    );
+    assert_eq!(api_url("http://example.com/xo"), "ws://example.com/xo/api/");
+    assert_eq!(api_url("ws://example.com/api"), "ws://example.com/api/");
+}
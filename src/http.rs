@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests;
+
+use std::io;
+
+use futures::{Stream, TryStreamExt};
+
+/// Build the HTTP URL of a download xo-server announced through `$getFrom`
+///
+/// `api_url` is the websocket URL the client connected to, like `wss://xo:8443/xo/api/`.
+/// xo-server serves downloads at paths like `/api/<id>` relative to its root, so the
+/// root is the path of `api_url` without its trailing `api/`. That way XO behind a
+/// reverse proxy on a subpath is handled too.
+pub(crate) fn download_url(api_url: &str, get_from: &str) -> Option<String> {
+    let (scheme, rest) = match api_url.split_once("://")? {
+        ("ws" | "http", rest) => ("http", rest),
+        ("wss" | "https", rest) => ("https", rest),
+        _ => return None,
+    };
+    let (authority, path) = rest.split_once('/').unwrap_or((rest, ""));
+    if authority.is_empty() {
+        return None;
+    }
+
+    let root = path.trim_end_matches('/');
+    // Only strip `api` when it is the whole last segment, not part of one like `/myapi`
+    let root = if root == "api" {
+        ""
+    } else {
+        root.strip_suffix("/api").unwrap_or(root)
+    };
+    let get_from = get_from.trim_start_matches("./").trim_start_matches('/');
+
+    Some(if root.is_empty() {
+        format!("{}://{}/{}", scheme, authority, get_from)
+    } else {
+        format!("{}://{}/{}/{}", scheme, authority, root, get_from)
+    })
+}
+
+/// Perform a GET request of `url` and stream the response body
+///
+/// This is used for downloads that xo-server serves over plain HTTP rather than through
+/// the JSON-RPC API. Responses with a status other than 2xx are reported as errors.
+pub(crate) async fn get(url: &str) -> io::Result<impl Stream<Item = io::Result<Vec<u8>>>> {
+    let to_io = |e: reqwest::Error| io::Error::new(io::ErrorKind::Other, e);
+
+    let response = reqwest::get(url)
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(to_io)?;
+
+    Ok(response
+        .bytes_stream()
+        .map_ok(|chunk| chunk.to_vec())
+        .map_err(to_io))
+}
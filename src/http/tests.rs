@@ -0,0 +1,108 @@
+use futures::TryStreamExt;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+use super::{download_url, get};
+
+#[test]
+fn download_urls() {
+    assert_eq!(
+        download_url("wss://xo.example.com/api/", "/api/abc123").as_deref(),
+        Some("https://xo.example.com/api/abc123")
+    );
+
+    // Port is kept
+    assert_eq!(
+        download_url("wss://xo:8443/api/", "/api/abc123").as_deref(),
+        Some("https://xo:8443/api/abc123")
+    );
+
+    // Path prefix of a reverse proxy is kept
+    assert_eq!(
+        download_url("wss://example.com/xo/api/", "/api/abc123").as_deref(),
+        Some("https://example.com/xo/api/abc123")
+    );
+    assert_eq!(
+        download_url("ws://example.com/xo/api", "./api/abc123").as_deref(),
+        Some("http://example.com/xo/api/abc123")
+    );
+
+    // Reverse proxy paths merely ending in `api` are kept whole
+    assert_eq!(
+        download_url("wss://example.com/myapi/", "/api/abc123").as_deref(),
+        Some("https://example.com/myapi/api/abc123")
+    );
+    assert_eq!(
+        download_url("wss://example.com/xoapi/api/", "/api/abc123").as_deref(),
+        Some("https://example.com/xoapi/api/abc123")
+    );
+
+    // IP addresses
+    assert_eq!(
+        download_url("wss://10.0.0.5/api/", "/api/abc123").as_deref(),
+        Some("https://10.0.0.5/api/abc123")
+    );
+    assert_eq!(
+        download_url("ws://[::1]:8080/api/", "/api/abc123").as_deref(),
+        Some("http://[::1]:8080/api/abc123")
+    );
+
+    assert_eq!(download_url("localhost:8080", "/api/abc123"), None);
+}
+
+/// Serve a single request with `response`, returning the address and the request as read
+async fn serve_once(
+    response: &'static [u8],
+) -> (std::net::SocketAddr, tokio::task::JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let handle = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let read = socket.read(&mut buf).await.unwrap();
+            request.extend_from_slice(&buf[..read]);
+        }
+        socket.write_all(response).await.unwrap();
+        socket.shutdown().await.unwrap();
+        String::from_utf8(request).unwrap()
+    });
+
+    (addr, handle)
+}
+
+#[tokio::test]
+async fn get_body() {
+    let (addr, request) =
+        serve_once(b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello").await;
+
+    let url = download_url(&format!("ws://{}/xo/api/", addr), "/api/abc123").unwrap();
+    let chunks: Vec<Vec<u8>> = get(&url).await.unwrap().try_collect().await.unwrap();
+    assert_eq!(chunks.concat(), b"hello");
+
+    let request = request.await.unwrap().to_ascii_lowercase();
+    assert!(
+        request.starts_with("get /xo/api/abc123 http/1.1\r\n"),
+        "{}",
+        request
+    );
+    assert!(
+        request.contains(&format!("host: {}\r\n", addr)),
+        "{}",
+        request
+    );
+}
+
+#[tokio::test]
+async fn error_status() {
+    let (addr, _request) =
+        serve_once(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+            .await;
+
+    let url = format!("http://{}/api/abc123", addr);
+    assert!(get(&url).await.is_err());
+}
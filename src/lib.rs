@@ -1,11 +1,22 @@
+mod any_object;
 pub mod api;
 pub mod credentials;
+mod date;
+mod http;
 mod object_type;
 mod types;
 
 #[macro_use]
 mod macros;
 
+// Make sure the output of xo-api-codegen compiles, it relies on the macros above
+#[cfg(test)]
+#[allow(dead_code)]
+#[rustfmt::skip]
+#[path = "../xo-api-codegen/snapshot/generated/mod.rs"]
+mod generated;
+
+pub use any_object::{AnyObject, AnyObjectMap, ObjectId};
 pub use api::Client;
 pub use jsonrpsee_types::{Error as RpcError, JsonValue};
 pub use object_type::ObjectType;
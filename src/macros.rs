@@ -80,9 +80,9 @@ macro_rules! impl_from_str {
 
 #[macro_export]
 macro_rules! impl_xo_object {
-    ($t:ty => $object_type:expr, $id:ty) => {
-        impl crate::types::XoObject for $t {
-            const OBJECT_TYPE: &'static str = $object_type;
+    ($t:ty => $object_type:ident, $id:ty) => {
+        impl $crate::types::XoObject for $t {
+            const OBJECT_TYPE: $crate::ObjectType = $crate::ObjectType::$object_type;
             type IdType = $id;
         }
     };
@@ -99,8 +99,8 @@ macro_rules! declare_id_type {
         #[serde(transparent)]
         $v struct $t(pub(crate) String);
 
-        impl crate::types::XoObjectId for $t {}
+        impl $crate::types::XoObjectId for $t {}
 
-        crate::impl_to_json_value!($t);
+        $crate::impl_to_json_value!($t);
     };
 }
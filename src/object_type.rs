@@ -1,20 +1,28 @@
+#[cfg(test)]
+mod tests;
+
+use std::{fmt, str::FromStr};
+
+use crate::types::Impossible;
 use jsonrpsee_types::JsonValue;
 
 /// Object type
 ///
-/// This is most often used with [`crate::Client::get_objects_of_type`] to specify what type
-/// of objects to fetch
+/// This is the value of the `type` field of objects returned by xo-server. It is most
+/// often used through [`crate::api::xo::XoProcedures::get_objects`] which filters on the
+/// `OBJECT_TYPE` of the requested object type.
 ///
-/// NOTE: This is only checked by running
-/// `xo-cli --list-objects --type | grep type | sort | uniq`
-/// on an existing XO-setup. Thus there may be more types
-// TODO: Check if there are more types
-#[derive(Debug)]
+/// Types not known by this crate are parsed as [`ObjectType::Unknown`] so no information
+/// is lost when talking to newer versions of xo-server.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ObjectType {
     GpuGroup,
 
     /// A virualization host, likely XCP-ng or similar
     Host,
+
+    /// Patch installed on, or available for, a host
+    HostPatch,
     Message,
     Network,
 
@@ -30,6 +38,12 @@ pub enum ObjectType {
     /// Pool of hosts
     Pool,
 
+    /// Patch known by a pool
+    PoolPatch,
+
+    /// Storage manager, the driver used by [`Self::Sr`]s
+    Sm,
+
     /// Storage repository - Place where the disks of VMs are stored ([`Self::Vdi`]s)
     Sr,
 
@@ -41,6 +55,12 @@ pub enum ObjectType {
     VdiSnapshot,
     VdiUnmanaged,
 
+    /// Virtual graphics card of a VM
+    Vgpu,
+
+    /// Type of virtual graphics card supported by a [`Self::Pgpu`]
+    VgpuType,
+
     /// Virtual network interface of a VM
     Vif,
 
@@ -53,13 +73,18 @@ pub enum ObjectType {
 
     /// Virtual machine template, used to easily create preconfigured VMs
     VmTemplate,
+
+    /// Object type not known by this crate
+    Unknown(String),
 }
 
-impl ToString for ObjectType {
-    fn to_string(&self) -> String {
+impl ObjectType {
+    /// Get the name of the type as used by xo-server
+    pub fn as_str(&self) -> &str {
         match self {
             ObjectType::GpuGroup => "gpuGroup",
             ObjectType::Host => "host",
+            ObjectType::HostPatch => "host-patch",
             ObjectType::Message => "message",
             ObjectType::Network => "network",
             ObjectType::Pbd => "PBD",
@@ -67,19 +92,80 @@ impl ToString for ObjectType {
             ObjectType::Pgpu => "PGPU",
             ObjectType::Pif => "PIF",
             ObjectType::Pool => "pool",
+            ObjectType::PoolPatch => "pool-patch",
+            ObjectType::Sm => "SM",
             ObjectType::Sr => "SR",
             ObjectType::Task => "task",
             ObjectType::Vbd => "VBD",
             ObjectType::Vdi => "VDI",
             ObjectType::VdiSnapshot => "VDI-snapshot",
             ObjectType::VdiUnmanaged => "VDI-unmanaged",
+            ObjectType::Vgpu => "VGPU",
+            ObjectType::VgpuType => "vgpuType",
             ObjectType::Vif => "VIF",
             ObjectType::Vm => "VM",
             ObjectType::VmController => "VM-controller",
             ObjectType::VmSnapshot => "VM-snapshot",
             ObjectType::VmTemplate => "VM-template",
+            ObjectType::Unknown(s) => s,
+        }
+    }
+}
+
+impl fmt::Display for ObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ObjectType {
+    type Err = Impossible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "gpuGroup" => ObjectType::GpuGroup,
+            "host" => ObjectType::Host,
+            "host-patch" => ObjectType::HostPatch,
+            "message" => ObjectType::Message,
+            "network" => ObjectType::Network,
+            "PBD" => ObjectType::Pbd,
+            "PCI" => ObjectType::Pci,
+            "PGPU" => ObjectType::Pgpu,
+            "PIF" => ObjectType::Pif,
+            "pool" => ObjectType::Pool,
+            "pool-patch" => ObjectType::PoolPatch,
+            "SM" => ObjectType::Sm,
+            "SR" => ObjectType::Sr,
+            "task" => ObjectType::Task,
+            "VBD" => ObjectType::Vbd,
+            "VDI" => ObjectType::Vdi,
+            "VDI-snapshot" => ObjectType::VdiSnapshot,
+            "VDI-unmanaged" => ObjectType::VdiUnmanaged,
+            "VGPU" => ObjectType::Vgpu,
+            "vgpuType" => ObjectType::VgpuType,
+            "VIF" => ObjectType::Vif,
+            "VM" => ObjectType::Vm,
+            "VM-controller" => ObjectType::VmController,
+            "VM-snapshot" => ObjectType::VmSnapshot,
+            "VM-template" => ObjectType::VmTemplate,
+            s => ObjectType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl serde::Serialize for ObjectType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ObjectType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        match s.parse() {
+            Ok(object_type) => Ok(object_type),
+            Err(impossible) => match impossible {},
         }
-        .to_string()
     }
 }
 
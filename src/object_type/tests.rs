@@ -0,0 +1,24 @@
+use super::ObjectType;
+
+#[test]
+fn round_trip() {
+    for s in ["VM", "VM-snapshot", "vgpuType", "host-patch", "SM"] {
+        let object_type: ObjectType = s.parse().unwrap();
+        assert!(!matches!(object_type, ObjectType::Unknown(_)));
+        assert_eq!(object_type.to_string(), s);
+    }
+
+    let unknown: ObjectType = "VTPM".parse().unwrap();
+    assert_eq!(unknown, ObjectType::Unknown("VTPM".to_string()));
+    assert_eq!(unknown.as_str(), "VTPM");
+}
+
+#[test]
+fn serde() {
+    let object_type: ObjectType = serde_json::from_str("\"VDI-snapshot\"").unwrap();
+    assert_eq!(object_type, ObjectType::VdiSnapshot);
+    assert_eq!(
+        serde_json::to_string(&ObjectType::PoolPatch).unwrap(),
+        "\"pool-patch\""
+    );
+}
@@ -2,6 +2,8 @@ use std::{collections, hash};
 
 use jsonrpsee_types::JsonValue;
 
+use crate::ObjectType;
+
 pub use jsonrpsee_types::Subscription;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -10,24 +12,29 @@ pub enum Impossible {}
 pub trait XoObjectId: serde::de::DeserializeOwned + Clone + Into<JsonValue> {}
 
 pub trait XoObject: serde::de::DeserializeOwned {
-    const OBJECT_TYPE: &'static str;
+    /// The value of the `type` field for objects of this type
+    const OBJECT_TYPE: ObjectType;
     type IdType: XoObjectId;
 }
 
+/// A type that can represent a collection of objects as returned by `xo.getAllObjects`
 pub trait XoObjectMap: serde::de::DeserializeOwned {
-    type Object: XoObject;
+    /// The type of objects held by the map, used to filter the request.
+    ///
+    /// `None` means that the map is able to hold objects of any type
+    const OBJECT_TYPE: Option<ObjectType>;
 }
 
 impl<T: XoObject> XoObjectMap for collections::BTreeMap<T::IdType, T>
 where
     <T as XoObject>::IdType: Ord,
 {
-    type Object = T;
+    const OBJECT_TYPE: Option<ObjectType> = Some(T::OBJECT_TYPE);
 }
 
 impl<T: XoObject> XoObjectMap for collections::HashMap<T::IdType, T>
 where
     <T as XoObject>::IdType: Eq + hash::Hash,
 {
-    type Object = T;
+    const OBJECT_TYPE: Option<ObjectType> = Some(T::OBJECT_TYPE);
 }
@@ -0,0 +1,75 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`AclProcedures::add`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct AclAddParams {
+    pub action: String,
+    pub object: String,
+    pub subject: String,
+}
+
+/// Parameters of [`AclProcedures::remove`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct AclRemoveParams {
+    pub action: String,
+    pub object: String,
+    pub subject: String,
+}
+
+pub struct AclProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl AclProcedures {
+    /// Add a new ACL entry
+    ///
+    /// xo-cli: acl.add action=<string> object=<string> subject=<string>
+    pub async fn add<R: DeserializeOwned>(
+        &self,
+        params: AclAddParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("acl.add", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get existing ACLs
+    ///
+    /// xo-cli: acl.get
+    pub async fn get<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("acl.get", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Get (explicit) permissions by object for the current user
+    ///
+    /// xo-cli: acl.getCurrentPermissions
+    pub async fn get_current_permissions<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("acl.getCurrentPermissions", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Remove an existing ACL entry
+    ///
+    /// xo-cli: acl.remove action=<string> object=<string> subject=<string>
+    pub async fn remove<R: DeserializeOwned>(
+        &self,
+        params: AclRemoveParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("acl.remove", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
@@ -0,0 +1,120 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`AuditProcedures::check_integrity`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct AuditCheckIntegrityParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub newest: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oldest: Option<String>,
+}
+
+/// Parameters of [`AuditProcedures::delete_range`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct AuditDeleteRangeParams {
+    pub newest: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oldest: Option<String>,
+}
+
+/// Parameters of [`AuditProcedures::generate_fingerprint`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct AuditGenerateFingerprintParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub newest: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oldest: Option<String>,
+}
+
+/// Parameters of [`AuditProcedures::get_records`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct AuditGetRecordsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ndjson: Option<bool>,
+}
+
+pub struct AuditProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl AuditProcedures {
+    /// Check records integrity between oldest and newest
+    ///
+    /// xo-cli: audit.checkIntegrity [newest=<string>] [oldest=<string>]
+    pub async fn check_integrity<R: DeserializeOwned>(
+        &self,
+        params: AuditCheckIntegrityParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("audit.checkIntegrity", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Clean audit database
+    ///
+    /// xo-cli: audit.clean
+    pub async fn clean<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("audit.clean", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Delete a range of records and rewrite the records chain
+    ///
+    /// xo-cli: audit.deleteRange newest=<string> [oldest=<string>]
+    pub async fn delete_range<R: DeserializeOwned>(
+        &self,
+        params: AuditDeleteRangeParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("audit.deleteRange", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: audit.exportRecords
+    pub async fn export_records<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("audit.exportRecords", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Generate a fingerprint of the chain oldest-newest
+    ///
+    /// xo-cli: audit.generateFingerprint [newest=<string>] [oldest=<string>]
+    pub async fn generate_fingerprint<R: DeserializeOwned>(
+        &self,
+        params: AuditGenerateFingerprintParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("audit.generateFingerprint", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get records from a passed record ID
+    ///
+    /// xo-cli: audit.getRecords [id=<string>] [ndjson=<boolean>]
+    pub async fn get_records<R: DeserializeOwned>(
+        &self,
+        params: AuditGetRecordsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("audit.getRecords", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
@@ -0,0 +1,320 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::RpcError;
+
+/// Parameters of [`BackupNgProcedures::create_job`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct BackupNgCreateJobParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compression: Option<JsonValue>,
+    pub mode: JsonValue,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remotes: Option<serde_json::Map<String, JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedules: Option<serde_json::Map<String, JsonValue>>,
+    pub settings: serde_json::Map<String, JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub srs: Option<serde_json::Map<String, JsonValue>>,
+    pub vms: serde_json::Map<String, JsonValue>,
+}
+
+/// Parameters of [`BackupNgProcedures::delete_job`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct BackupNgDeleteJobParams {
+    pub id: String,
+}
+
+/// Parameters of [`BackupNgProcedures::delete_vm_backup`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct BackupNgDeleteVmBackupParams {
+    pub id: String,
+}
+
+/// Parameters of [`BackupNgProcedures::edit_job`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct BackupNgEditJobParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compression: Option<JsonValue>,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remotes: Option<serde_json::Map<String, JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<serde_json::Map<String, JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub srs: Option<serde_json::Map<String, JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vms: Option<serde_json::Map<String, JsonValue>>,
+}
+
+/// Parameters of [`BackupNgProcedures::fetch_files`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct BackupNgFetchFilesParams {
+    pub disk: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<String>,
+    pub paths: Vec<JsonValue>,
+    pub remote: String,
+}
+
+/// Parameters of [`BackupNgProcedures::get_all_logs`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct BackupNgGetAllLogsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ndjson: Option<bool>,
+}
+
+/// Parameters of [`BackupNgProcedures::get_job`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct BackupNgGetJobParams {
+    pub id: String,
+}
+
+/// Parameters of [`BackupNgProcedures::get_logs`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct BackupNgGetLogsParams {
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<f64>,
+}
+
+/// Parameters of [`BackupNgProcedures::import_vm_backup`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct BackupNgImportVmBackupParams {
+    pub id: String,
+    pub settings: serde_json::Map<String, JsonValue>,
+    pub sr: String,
+}
+
+/// Parameters of [`BackupNgProcedures::list_files`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct BackupNgListFilesParams {
+    pub disk: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<String>,
+    pub path: String,
+    pub remote: String,
+}
+
+/// Parameters of [`BackupNgProcedures::list_partitions`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct BackupNgListPartitionsParams {
+    pub disk: String,
+    pub remote: String,
+}
+
+/// Parameters of [`BackupNgProcedures::list_vm_backups`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct BackupNgListVmBackupsParams {
+    #[serde(rename = "_forceRefresh")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force_refresh: Option<bool>,
+    pub remotes: Vec<JsonValue>,
+}
+
+/// Parameters of [`BackupNgProcedures::run_job`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct BackupNgRunJobParams {
+    pub id: String,
+    pub schedule: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<serde_json::Map<String, JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vm: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vms: Option<Vec<JsonValue>>,
+}
+
+pub struct BackupNgProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl BackupNgProcedures {
+    /// xo-cli: backupNg.createJob [compression=<any>] mode=<any> [name=<string>] [proxy=<string>] [remotes=<object>] [schedules=<object>] settings=<object> [srs=<object>] vms=<object>
+    pub async fn create_job<R: DeserializeOwned>(
+        &self,
+        params: BackupNgCreateJobParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("backupNg.createJob", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: backupNg.deleteJob id=<string>
+    pub async fn delete_job<R: DeserializeOwned>(
+        &self,
+        params: BackupNgDeleteJobParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("backupNg.deleteJob", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: backupNg.deleteVmBackup id=<string>
+    pub async fn delete_vm_backup<R: DeserializeOwned>(
+        &self,
+        params: BackupNgDeleteVmBackupParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("backupNg.deleteVmBackup", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: backupNg.editJob [compression=<any>] id=<string> [mode=<any>] [name=<string>] [proxy=<string|null>] [remotes=<object>] [settings=<object>] [srs=<object>] [vms=<object>]
+    pub async fn edit_job<R: DeserializeOwned>(
+        &self,
+        params: BackupNgEditJobParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("backupNg.editJob", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: backupNg.fetchFiles disk=<string> [partition=<string>] paths=<array> remote=<string>
+    pub async fn fetch_files<R: DeserializeOwned>(
+        &self,
+        params: BackupNgFetchFilesParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("backupNg.fetchFiles", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: backupNg.getAllJobs
+    pub async fn get_all_jobs<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("backupNg.getAllJobs", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// xo-cli: backupNg.getAllLogs [ndjson=<boolean>]
+    pub async fn get_all_logs<R: DeserializeOwned>(
+        &self,
+        params: BackupNgGetAllLogsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("backupNg.getAllLogs", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: backupNg.getJob id=<string>
+    pub async fn get_job<R: DeserializeOwned>(
+        &self,
+        params: BackupNgGetJobParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("backupNg.getJob", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: backupNg.getLogs [after=<number|string>] [before=<number|string>] [limit=<number>] *=<any>
+    pub async fn get_logs<R: DeserializeOwned>(
+        &self,
+        params: BackupNgGetLogsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("backupNg.getLogs", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: backupNg.getSuggestedExcludedTags
+    pub async fn get_suggested_excluded_tags<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("backupNg.getSuggestedExcludedTags", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// xo-cli: backupNg.importVmBackup id=<string> settings=<object> sr=<string>
+    pub async fn import_vm_backup<R: DeserializeOwned>(
+        &self,
+        params: BackupNgImportVmBackupParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("backupNg.importVmBackup", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: backupNg.listFiles disk=<string> [partition=<string>] path=<string> remote=<string>
+    pub async fn list_files<R: DeserializeOwned>(
+        &self,
+        params: BackupNgListFilesParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("backupNg.listFiles", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: backupNg.listPartitions disk=<string> remote=<string>
+    pub async fn list_partitions<R: DeserializeOwned>(
+        &self,
+        params: BackupNgListPartitionsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("backupNg.listPartitions", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: backupNg.listVmBackups [_forceRefresh=<boolean>] remotes=<array>
+    pub async fn list_vm_backups<R: DeserializeOwned>(
+        &self,
+        params: BackupNgListVmBackupsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("backupNg.listVmBackups", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: backupNg.runJob id=<string> schedule=<string> [settings=<object>] [vm=<string>] [vms=<array>]
+    pub async fn run_job<R: DeserializeOwned>(
+        &self,
+        params: BackupNgRunJobParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("backupNg.runJob", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
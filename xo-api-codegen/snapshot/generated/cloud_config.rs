@@ -0,0 +1,87 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`CloudConfigProcedures::create`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct CloudConfigCreateParams {
+    pub name: String,
+    pub template: String,
+}
+
+/// Parameters of [`CloudConfigProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct CloudConfigDeleteParams {
+    pub id: String,
+}
+
+/// Parameters of [`CloudConfigProcedures::update`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct CloudConfigUpdateParams {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+pub struct CloudConfigProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl CloudConfigProcedures {
+    /// Creates a new cloud config template
+    ///
+    /// xo-cli: cloudConfig.create name=<string> template=<string>
+    pub async fn create<R: DeserializeOwned>(
+        &self,
+        params: CloudConfigCreateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("cloudConfig.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Deletes an existing cloud config template
+    ///
+    /// xo-cli: cloudConfig.delete id=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: CloudConfigDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("cloudConfig.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Gets all existing cloud configs templates
+    ///
+    /// xo-cli: cloudConfig.getAll
+    pub async fn get_all<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("cloudConfig.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Modifies an existing cloud config template
+    ///
+    /// xo-cli: cloudConfig.update id=<string> [name=<string>] [template=<string>]
+    pub async fn update<R: DeserializeOwned>(
+        &self,
+        params: CloudConfigUpdateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("cloudConfig.update", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
@@ -0,0 +1,78 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`CustomFieldProcedures::add`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct CustomFieldAddParams {
+    pub id: String,
+    pub name: String,
+    pub value: String,
+}
+
+/// Parameters of [`CustomFieldProcedures::remove`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct CustomFieldRemoveParams {
+    pub id: String,
+    pub name: String,
+}
+
+/// Parameters of [`CustomFieldProcedures::set`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct CustomFieldSetParams {
+    pub id: String,
+    pub name: String,
+    pub value: String,
+}
+
+pub struct CustomFieldProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl CustomFieldProcedures {
+    /// Add a new custom field to an object
+    ///
+    /// xo-cli: customField.add id=<string> name=<string> value=<string>
+    pub async fn add<R: DeserializeOwned>(
+        &self,
+        params: CustomFieldAddParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("customField.add", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Remove an existing custom field from an object
+    ///
+    /// xo-cli: customField.remove id=<string> name=<string>
+    pub async fn remove<R: DeserializeOwned>(
+        &self,
+        params: CustomFieldRemoveParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("customField.remove", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Set a custom field
+    ///
+    /// xo-cli: customField.set id=<string> name=<string> value=<string>
+    pub async fn set<R: DeserializeOwned>(
+        &self,
+        params: CustomFieldSetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("customField.set", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
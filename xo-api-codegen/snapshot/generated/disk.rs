@@ -0,0 +1,108 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`DiskProcedures::create`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct DiskCreateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bootable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<String>,
+    pub size: JsonValue,
+    pub sr: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vm: Option<String>,
+}
+
+/// Parameters of [`DiskProcedures::export_content`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct DiskExportContentParams {
+    pub id: String,
+}
+
+/// Parameters of [`DiskProcedures::import`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct DiskImportParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub name: String,
+    pub sr: String,
+    pub r#type: String,
+    #[serde(rename = "vmdkData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vmdk_data: Option<serde_json::Map<String, JsonValue>>,
+}
+
+/// Parameters of [`DiskProcedures::import_content`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct DiskImportContentParams {
+    pub id: String,
+}
+
+pub struct DiskProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl DiskProcedures {
+    /// Create a new disk on a SR
+    ///
+    /// xo-cli: disk.create [bootable=<boolean>] [mode=<string>] name=<string> [position=<string>] size=<integer|string> sr=<string> [vm=<string>]
+    pub async fn create<R: DeserializeOwned>(
+        &self,
+        params: DiskCreateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("disk.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Export the content of a VDI
+    ///
+    /// xo-cli: disk.exportContent id=<string>
+    pub async fn export_content<R: DeserializeOwned>(
+        &self,
+        params: DiskExportContentParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("disk.exportContent", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: disk.import [description=<string>] name=<string> sr=<string> type=<string> [vmdkData=<object>]
+    pub async fn import<R: DeserializeOwned>(
+        &self,
+        params: DiskImportParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("disk.import", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Import contents into a VDI
+    ///
+    /// xo-cli: disk.importContent id=<string>
+    pub async fn import_content<R: DeserializeOwned>(
+        &self,
+        params: DiskImportContentParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("disk.importContent", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
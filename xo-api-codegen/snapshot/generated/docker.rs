@@ -0,0 +1,148 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`DockerProcedures::deregister`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct DockerDeregisterParams {
+    pub vm: String,
+}
+
+/// Parameters of [`DockerProcedures::pause`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct DockerPauseParams {
+    pub container: String,
+    pub vm: String,
+}
+
+/// Parameters of [`DockerProcedures::register`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct DockerRegisterParams {
+    pub vm: String,
+}
+
+/// Parameters of [`DockerProcedures::restart`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct DockerRestartParams {
+    pub container: String,
+    pub vm: String,
+}
+
+/// Parameters of [`DockerProcedures::start`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct DockerStartParams {
+    pub container: String,
+    pub vm: String,
+}
+
+/// Parameters of [`DockerProcedures::stop`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct DockerStopParams {
+    pub container: String,
+    pub vm: String,
+}
+
+/// Parameters of [`DockerProcedures::unpause`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct DockerUnpauseParams {
+    pub container: String,
+    pub vm: String,
+}
+
+pub struct DockerProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl DockerProcedures {
+    /// Deregister the VM for Docker management
+    ///
+    /// xo-cli: docker.deregister vm=<string>
+    pub async fn deregister<R: DeserializeOwned>(
+        &self,
+        params: DockerDeregisterParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("docker.deregister", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: docker.pause container=<string> vm=<string>
+    pub async fn pause<R: DeserializeOwned>(
+        &self,
+        params: DockerPauseParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("docker.pause", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Register the VM for Docker management
+    ///
+    /// xo-cli: docker.register vm=<string>
+    pub async fn register<R: DeserializeOwned>(
+        &self,
+        params: DockerRegisterParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("docker.register", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: docker.restart container=<string> vm=<string>
+    pub async fn restart<R: DeserializeOwned>(
+        &self,
+        params: DockerRestartParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("docker.restart", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: docker.start container=<string> vm=<string>
+    pub async fn start<R: DeserializeOwned>(
+        &self,
+        params: DockerStartParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("docker.start", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: docker.stop container=<string> vm=<string>
+    pub async fn stop<R: DeserializeOwned>(
+        &self,
+        params: DockerStopParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("docker.stop", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: docker.unpause container=<string> vm=<string>
+    pub async fn unpause<R: DeserializeOwned>(
+        &self,
+        params: DockerUnpauseParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("docker.unpause", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
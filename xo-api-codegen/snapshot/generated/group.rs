@@ -0,0 +1,150 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`GroupProcedures::add_user`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct GroupAddUserParams {
+    pub id: String,
+    #[serde(rename = "userId")]
+    pub user_id: String,
+}
+
+/// Parameters of [`GroupProcedures::create`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct GroupCreateParams {
+    pub name: String,
+}
+
+/// Parameters of [`GroupProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct GroupDeleteParams {
+    pub id: String,
+}
+
+/// Parameters of [`GroupProcedures::remove_user`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct GroupRemoveUserParams {
+    pub id: String,
+    #[serde(rename = "userId")]
+    pub user_id: String,
+}
+
+/// Parameters of [`GroupProcedures::set`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct GroupSetParams {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Parameters of [`GroupProcedures::set_users`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct GroupSetUsersParams {
+    pub id: String,
+    #[serde(rename = "userIds")]
+    pub user_ids: JsonValue,
+}
+
+pub struct GroupProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl GroupProcedures {
+    /// Adds a user to a group
+    ///
+    /// xo-cli: group.addUser id=<string> userId=<string>
+    pub async fn add_user<R: DeserializeOwned>(
+        &self,
+        params: GroupAddUserParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("group.addUser", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Creates a new group
+    ///
+    /// xo-cli: group.create name=<string>
+    pub async fn create<R: DeserializeOwned>(
+        &self,
+        params: GroupCreateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("group.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Deletes an existing group
+    ///
+    /// xo-cli: group.delete id=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: GroupDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("group.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Returns all the existing group
+    ///
+    /// xo-cli: group.getAll
+    pub async fn get_all<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("group.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Removes a user from a group
+    ///
+    /// xo-cli: group.removeUser id=<string> userId=<string>
+    pub async fn remove_user<R: DeserializeOwned>(
+        &self,
+        params: GroupRemoveUserParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("group.removeUser", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Changes the properties of an existing group
+    ///
+    /// xo-cli: group.set id=<string> [name=<string>]
+    pub async fn set<R: DeserializeOwned>(
+        &self,
+        params: GroupSetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("group.set", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Sets the users belonging to a group
+    ///
+    /// xo-cli: group.setUsers id=<string> userIds=<any>
+    pub async fn set_users<R: DeserializeOwned>(
+        &self,
+        params: GroupSetUsersParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("group.setUsers", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
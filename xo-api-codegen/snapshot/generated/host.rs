@@ -0,0 +1,473 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`HostProcedures::detach`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostDetachParams {
+    pub id: String,
+}
+
+/// Parameters of [`HostProcedures::disable`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostDisableParams {
+    pub id: String,
+}
+
+/// Parameters of [`HostProcedures::emergency_shutdown_host`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostEmergencyShutdownHostParams {
+    pub host: String,
+}
+
+/// Parameters of [`HostProcedures::enable`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostEnableParams {
+    pub id: String,
+}
+
+/// Parameters of [`HostProcedures::forget`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostForgetParams {
+    pub id: String,
+}
+
+/// Parameters of [`HostProcedures::get_scheduler_granularity`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostGetSchedulerGranularityParams {
+    pub id: String,
+}
+
+/// Parameters of [`HostProcedures::install_certificate`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostInstallCertificateParams {
+    pub certificate: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain: Option<String>,
+    pub id: String,
+    #[serde(rename = "privateKey")]
+    pub private_key: String,
+}
+
+/// Parameters of [`HostProcedures::install_supplemental_pack`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostInstallSupplementalPackParams {
+    pub host: String,
+}
+
+/// Parameters of [`HostProcedures::is_host_server_time_consistent`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostIsHostServerTimeConsistentParams {
+    pub host: String,
+}
+
+/// Parameters of [`HostProcedures::is_hyper_threading_enabled`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostIsHyperThreadingEnabledParams {
+    pub id: String,
+}
+
+/// Parameters of [`HostProcedures::restart`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostRestartParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    pub id: String,
+}
+
+/// Parameters of [`HostProcedures::restart_agent`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostRestartAgentParams {
+    pub id: String,
+}
+
+/// Parameters of [`HostProcedures::restart_agent2`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostRestartAgent2Params {
+    pub id: String,
+}
+
+/// Parameters of [`HostProcedures::scan_pifs`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostScanPifsParams {
+    pub id: String,
+}
+
+/// Parameters of [`HostProcedures::set`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostSetParams {
+    pub id: String,
+    #[serde(rename = "iscsiIqn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iscsi_iqn: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multipathing: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_label: Option<String>,
+}
+
+/// Parameters of [`HostProcedures::set_control_domain_memory`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostSetControlDomainMemoryParams {
+    pub id: String,
+    pub memory: f64,
+}
+
+/// Parameters of [`HostProcedures::set_maintenance_mode`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostSetMaintenanceModeParams {
+    pub id: String,
+    pub maintenance: bool,
+}
+
+/// Parameters of [`HostProcedures::set_remote_syslog_host`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostSetRemoteSyslogHostParams {
+    pub id: String,
+    #[serde(rename = "syslogDestination")]
+    pub syslog_destination: String,
+}
+
+/// Parameters of [`HostProcedures::set_scheduler_granularity`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostSetSchedulerGranularityParams {
+    pub id: String,
+    #[serde(rename = "schedulerGranularity")]
+    pub scheduler_granularity: JsonValue,
+}
+
+/// Parameters of [`HostProcedures::start`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostStartParams {
+    pub id: String,
+}
+
+/// Parameters of [`HostProcedures::stats`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostStatsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granularity: Option<String>,
+    pub host: String,
+}
+
+/// Parameters of [`HostProcedures::stop`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct HostStopParams {
+    pub id: String,
+}
+
+pub struct HostProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl HostProcedures {
+    /// Eject the host of a pool
+    ///
+    /// xo-cli: host.detach id=<string>
+    pub async fn detach<R: DeserializeOwned>(
+        &self,
+        params: HostDetachParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.detach", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Disable to create VM on the hsot
+    ///
+    /// xo-cli: host.disable id=<string>
+    pub async fn disable<R: DeserializeOwned>(
+        &self,
+        params: HostDisableParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.disable", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Suspend all VMs and shutdown host
+    ///
+    /// xo-cli: host.emergencyShutdownHost host=<string>
+    pub async fn emergency_shutdown_host<R: DeserializeOwned>(
+        &self,
+        params: HostEmergencyShutdownHostParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.emergencyShutdownHost", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Enable to create VM on the host
+    ///
+    /// xo-cli: host.enable id=<string>
+    pub async fn enable<R: DeserializeOwned>(
+        &self,
+        params: HostEnableParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.enable", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Remove the host record from XAPI database
+    ///
+    /// xo-cli: host.forget id=<string>
+    pub async fn forget<R: DeserializeOwned>(
+        &self,
+        params: HostForgetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.forget", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get the scheduler granularity of a host
+    ///
+    /// xo-cli: host.getSchedulerGranularity id=<string>
+    pub async fn get_scheduler_granularity<R: DeserializeOwned>(
+        &self,
+        params: HostGetSchedulerGranularityParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.getSchedulerGranularity", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Install a certificate on a host
+    ///
+    /// xo-cli: host.installCertificate certificate=<string> [chain=<string>] id=<string> privateKey=<string>
+    pub async fn install_certificate<R: DeserializeOwned>(
+        &self,
+        params: HostInstallCertificateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.installCertificate", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Installs supplemental pack from ISO file
+    ///
+    /// xo-cli: host.installSupplementalPack host=<string>
+    pub async fn install_supplemental_pack<R: DeserializeOwned>(
+        &self,
+        params: HostInstallSupplementalPackParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.installSupplementalPack", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: host.isHostServerTimeConsistent host=<string>
+    pub async fn is_host_server_time_consistent<R: DeserializeOwned>(
+        &self,
+        params: HostIsHostServerTimeConsistentParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.isHostServerTimeConsistent", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get hyper-threading information
+    ///
+    /// xo-cli: host.isHyperThreadingEnabled id=<string>
+    pub async fn is_hyper_threading_enabled<R: DeserializeOwned>(
+        &self,
+        params: HostIsHyperThreadingEnabledParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.isHyperThreadingEnabled", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Restart the host
+    ///
+    /// xo-cli: host.restart [force=<boolean>] id=<string>
+    pub async fn restart<R: DeserializeOwned>(
+        &self,
+        params: HostRestartParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.restart", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Restart the Xen agent on the host
+    ///
+    /// xo-cli: host.restartAgent id=<string>
+    pub async fn restart_agent<R: DeserializeOwned>(
+        &self,
+        params: HostRestartAgentParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.restartAgent", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Restart the Xen agent on the host
+    ///
+    /// xo-cli: host.restart_agent id=<string>
+    pub async fn restart_agent2<R: DeserializeOwned>(
+        &self,
+        params: HostRestartAgent2Params,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.restart_agent", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Refresh the list of physical interfaces for this host
+    ///
+    /// xo-cli: host.scanPifs id=<string>
+    pub async fn scan_pifs<R: DeserializeOwned>(
+        &self,
+        params: HostScanPifsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.scanPifs", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Changes the properties of an host
+    ///
+    /// xo-cli: host.set id=<string> [iscsiIqn=<string>] [multipathing=<boolean>] [name_description=<string>] [name_label=<string>]
+    pub async fn set<R: DeserializeOwned>(
+        &self,
+        params: HostSetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.set", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Set host's control domain memory and restart the host
+    ///
+    /// xo-cli: host.setControlDomainMemory id=<string> memory=<number>
+    pub async fn set_control_domain_memory<R: DeserializeOwned>(
+        &self,
+        params: HostSetControlDomainMemoryParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.setControlDomainMemory", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Manage the maintenance mode
+    ///
+    /// xo-cli: host.setMaintenanceMode id=<string> maintenance=<boolean>
+    pub async fn set_maintenance_mode<R: DeserializeOwned>(
+        &self,
+        params: HostSetMaintenanceModeParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.setMaintenanceMode", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: host.setRemoteSyslogHost id=<string> syslogDestination=<string>
+    pub async fn set_remote_syslog_host<R: DeserializeOwned>(
+        &self,
+        params: HostSetRemoteSyslogHostParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.setRemoteSyslogHost", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Set scheduler granularity of a host
+    ///
+    /// xo-cli: host.setSchedulerGranularity id=<string> schedulerGranularity=<any>
+    pub async fn set_scheduler_granularity<R: DeserializeOwned>(
+        &self,
+        params: HostSetSchedulerGranularityParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.setSchedulerGranularity", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Start the host
+    ///
+    /// xo-cli: host.start id=<string>
+    pub async fn start<R: DeserializeOwned>(
+        &self,
+        params: HostStartParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.start", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Returns statistic of the host
+    ///
+    /// xo-cli: host.stats [granularity=<string>] host=<string>
+    pub async fn stats<R: DeserializeOwned>(
+        &self,
+        params: HostStatsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.stats", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Stop the host
+    ///
+    /// xo-cli: host.stop id=<string>
+    pub async fn stop<R: DeserializeOwned>(
+        &self,
+        params: HostStopParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("host.stop", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
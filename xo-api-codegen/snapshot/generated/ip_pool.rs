@@ -0,0 +1,49 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+pub struct IpPoolProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl IpPoolProcedures {
+    /// Creates a new ipPool
+    ///
+    /// xo-cli: ipPool.create
+    pub async fn create<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("ipPool.create", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Delete an ipPool
+    ///
+    /// xo-cli: ipPool.delete
+    pub async fn delete<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("ipPool.delete", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// List all ipPools
+    ///
+    /// xo-cli: ipPool.getAll
+    pub async fn get_all<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("ipPool.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Allow to modify an existing ipPool
+    ///
+    /// xo-cli: ipPool.set
+    pub async fn set<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("ipPool.set", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+}
@@ -0,0 +1,132 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`JobProcedures::create`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct JobCreateParams {
+    pub job: serde_json::Map<String, JsonValue>,
+}
+
+/// Parameters of [`JobProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct JobDeleteParams {
+    pub id: String,
+}
+
+/// Parameters of [`JobProcedures::get`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct JobGetParams {
+    pub id: String,
+}
+
+/// Parameters of [`JobProcedures::run_sequence`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct JobRunSequenceParams {
+    #[serde(rename = "idSequence")]
+    pub id_sequence: Vec<JsonValue>,
+}
+
+/// Parameters of [`JobProcedures::set`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct JobSetParams {
+    pub job: serde_json::Map<String, JsonValue>,
+}
+
+pub struct JobProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl JobProcedures {
+    /// Cancel a current run
+    ///
+    /// xo-cli: job.cancel
+    pub async fn cancel<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("job.cancel", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Creates a new job from description object
+    ///
+    /// xo-cli: job.create job=<object>
+    pub async fn create<R: DeserializeOwned>(
+        &self,
+        params: JobCreateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("job.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Deletes an existing job
+    ///
+    /// xo-cli: job.delete id=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: JobDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("job.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Gets an existing job
+    ///
+    /// xo-cli: job.get id=<string>
+    pub async fn get<R: DeserializeOwned>(
+        &self,
+        params: JobGetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("job.get", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Gets all available jobs
+    ///
+    /// xo-cli: job.getAll
+    pub async fn get_all<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("job.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Runs jobs sequentially, in the provided order
+    ///
+    /// xo-cli: job.runSequence idSequence=<array>
+    pub async fn run_sequence<R: DeserializeOwned>(
+        &self,
+        params: JobRunSequenceParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("job.runSequence", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Modifies an existing job from a description object
+    ///
+    /// xo-cli: job.set job=<object>
+    pub async fn set<R: DeserializeOwned>(
+        &self,
+        params: JobSetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("job.set", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
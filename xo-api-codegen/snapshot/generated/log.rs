@@ -0,0 +1,54 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`LogProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct LogDeleteParams {
+    pub id: JsonValue,
+    pub namespace: String,
+}
+
+/// Parameters of [`LogProcedures::get`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct LogGetParams {
+    pub namespace: String,
+}
+
+pub struct LogProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl LogProcedures {
+    /// Deletes one or several logs from a namespace
+    ///
+    /// xo-cli: log.delete id=<array|string> namespace=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: LogDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("log.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Returns logs list for one namespace
+    ///
+    /// xo-cli: log.get namespace=<string>
+    pub async fn get<R: DeserializeOwned>(
+        &self,
+        params: LogGetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("log.get", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
@@ -0,0 +1,31 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`MessageProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct MessageDeleteParams {
+    pub id: String,
+}
+
+pub struct MessageProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl MessageProcedures {
+    /// xo-cli: message.delete id=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: MessageDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("message.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
@@ -0,0 +1,189 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`MetadataBackupProcedures::create_job`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct MetadataBackupCreateJobParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pools: Option<serde_json::Map<String, JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    pub remotes: serde_json::Map<String, JsonValue>,
+    pub schedules: serde_json::Map<String, JsonValue>,
+    pub settings: serde_json::Map<String, JsonValue>,
+    #[serde(rename = "xoMetadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xo_metadata: Option<bool>,
+}
+
+/// Parameters of [`MetadataBackupProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct MetadataBackupDeleteParams {
+    pub id: String,
+}
+
+/// Parameters of [`MetadataBackupProcedures::delete_job`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct MetadataBackupDeleteJobParams {
+    pub id: String,
+}
+
+/// Parameters of [`MetadataBackupProcedures::edit_job`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct MetadataBackupEditJobParams {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pools: Option<Option<serde_json::Map<String, JsonValue>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remotes: Option<serde_json::Map<String, JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<serde_json::Map<String, JsonValue>>,
+    #[serde(rename = "xoMetadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xo_metadata: Option<bool>,
+}
+
+/// Parameters of [`MetadataBackupProcedures::get_job`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct MetadataBackupGetJobParams {
+    pub id: String,
+}
+
+/// Parameters of [`MetadataBackupProcedures::list`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct MetadataBackupListParams {
+    pub remotes: Vec<JsonValue>,
+}
+
+/// Parameters of [`MetadataBackupProcedures::restore`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct MetadataBackupRestoreParams {
+    pub id: String,
+}
+
+/// Parameters of [`MetadataBackupProcedures::run_job`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct MetadataBackupRunJobParams {
+    pub id: String,
+    pub schedule: String,
+}
+
+pub struct MetadataBackupProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl MetadataBackupProcedures {
+    /// xo-cli: metadataBackup.createJob [name=<string>] [pools=<object>] [proxy=<string>] remotes=<object> schedules=<object> settings=<object> [xoMetadata=<boolean>]
+    pub async fn create_job<R: DeserializeOwned>(
+        &self,
+        params: MetadataBackupCreateJobParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("metadataBackup.createJob", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: metadataBackup.delete id=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: MetadataBackupDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("metadataBackup.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: metadataBackup.deleteJob id=<string>
+    pub async fn delete_job<R: DeserializeOwned>(
+        &self,
+        params: MetadataBackupDeleteJobParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("metadataBackup.deleteJob", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: metadataBackup.editJob id=<string> [name=<string>] [pools=<object|null>] [proxy=<string|null>] [remotes=<object>] [settings=<object>] [xoMetadata=<boolean>]
+    pub async fn edit_job<R: DeserializeOwned>(
+        &self,
+        params: MetadataBackupEditJobParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("metadataBackup.editJob", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: metadataBackup.getAllJobs
+    pub async fn get_all_jobs<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("metadataBackup.getAllJobs", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// xo-cli: metadataBackup.getJob id=<string>
+    pub async fn get_job<R: DeserializeOwned>(
+        &self,
+        params: MetadataBackupGetJobParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("metadataBackup.getJob", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: metadataBackup.list remotes=<array>
+    pub async fn list<R: DeserializeOwned>(
+        &self,
+        params: MetadataBackupListParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("metadataBackup.list", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: metadataBackup.restore id=<string>
+    pub async fn restore<R: DeserializeOwned>(
+        &self,
+        params: MetadataBackupRestoreParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("metadataBackup.restore", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: metadataBackup.runJob id=<string> schedule=<string>
+    pub async fn run_job<R: DeserializeOwned>(
+        &self,
+        params: MetadataBackupRunJobParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("metadataBackup.runJob", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
@@ -0,0 +1,41 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+pub mod acl;
+pub mod audit;
+pub mod backup_ng;
+pub mod cloud_config;
+pub mod custom_field;
+pub mod disk;
+pub mod docker;
+pub mod group;
+pub mod host;
+pub mod ip_pool;
+pub mod job;
+pub mod log;
+pub mod message;
+pub mod metadata_backup;
+pub mod network;
+pub mod pbd;
+pub mod pif;
+pub mod plugin;
+pub mod pool;
+pub mod proxy;
+pub mod remote;
+pub mod resource_set;
+pub mod role;
+pub mod schedule;
+pub mod server;
+pub mod session;
+pub mod sr;
+pub mod system;
+pub mod tag;
+pub mod task;
+pub mod test;
+pub mod token;
+pub mod user;
+pub mod vbd;
+pub mod vdi;
+pub mod vif;
+pub mod vm;
+pub mod xo;
+pub mod xosan;
@@ -0,0 +1,138 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`NetworkProcedures::create`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct NetworkCreateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtu: Option<JsonValue>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pif: Option<String>,
+    pub pool: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vlan: Option<JsonValue>,
+}
+
+/// Parameters of [`NetworkProcedures::create_bonded`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct NetworkCreateBondedParams {
+    #[serde(rename = "bondMode")]
+    pub bond_mode: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtu: Option<JsonValue>,
+    pub name: String,
+    pub pifs: Vec<JsonValue>,
+    pub pool: String,
+}
+
+/// Parameters of [`NetworkProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct NetworkDeleteParams {
+    pub id: String,
+}
+
+/// Parameters of [`NetworkProcedures::delete2`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct NetworkDelete2Params {
+    pub id: String,
+}
+
+/// Parameters of [`NetworkProcedures::set`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct NetworkSetParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic: Option<bool>,
+    #[serde(rename = "defaultIsLocked")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_is_locked: Option<bool>,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_label: Option<String>,
+}
+
+pub struct NetworkProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl NetworkProcedures {
+    /// xo-cli: network.create [description=<string>] [mtu=<integer|string>] name=<string> [pif=<string>] pool=<string> [vlan=<integer|string>]
+    pub async fn create<R: DeserializeOwned>(
+        &self,
+        params: NetworkCreateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("network.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Create a bonded network. bondMode can be balance-slb, active-backup or lacp
+    ///
+    /// xo-cli: network.createBonded bondMode=<string> [description=<string>] [mtu=<integer|string>] name=<string> pifs=<array> pool=<string>
+    pub async fn create_bonded<R: DeserializeOwned>(
+        &self,
+        params: NetworkCreateBondedParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("network.createBonded", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: network.delete id=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: NetworkDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("network.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: network.delete_ id=<string>
+    pub async fn delete2<R: DeserializeOwned>(
+        &self,
+        params: NetworkDelete2Params,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("network.delete_", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: network.getBondModes
+    pub async fn get_bond_modes<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("network.getBondModes", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// xo-cli: network.set [automatic=<boolean>] [defaultIsLocked=<boolean>] id=<string> [name_description=<string>] [name_label=<string>]
+    pub async fn set<R: DeserializeOwned>(
+        &self,
+        params: NetworkSetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("network.set", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
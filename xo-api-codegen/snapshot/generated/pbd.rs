@@ -0,0 +1,67 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`PbdProcedures::connect`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PbdConnectParams {
+    pub id: String,
+}
+
+/// Parameters of [`PbdProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PbdDeleteParams {
+    pub id: String,
+}
+
+/// Parameters of [`PbdProcedures::disconnect`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PbdDisconnectParams {
+    pub id: String,
+}
+
+pub struct PbdProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl PbdProcedures {
+    /// xo-cli: pbd.connect id=<string>
+    pub async fn connect<R: DeserializeOwned>(
+        &self,
+        params: PbdConnectParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pbd.connect", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: pbd.delete id=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: PbdDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pbd.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: pbd.disconnect id=<string>
+    pub async fn disconnect<R: DeserializeOwned>(
+        &self,
+        params: PbdDisconnectParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pbd.disconnect", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
@@ -0,0 +1,129 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`PifProcedures::connect`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PifConnectParams {
+    pub id: String,
+}
+
+/// Parameters of [`PifProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PifDeleteParams {
+    pub id: String,
+}
+
+/// Parameters of [`PifProcedures::disconnect`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PifDisconnectParams {
+    pub id: String,
+}
+
+/// Parameters of [`PifProcedures::edit_pif`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PifEditPifParams {
+    pub id: String,
+    pub vlan: JsonValue,
+}
+
+/// Parameters of [`PifProcedures::reconfigure_ip`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PifReconfigureIpParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dns: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub netmask: Option<String>,
+}
+
+pub struct PifProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl PifProcedures {
+    /// xo-cli: pif.connect id=<string>
+    pub async fn connect<R: DeserializeOwned>(
+        &self,
+        params: PifConnectParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pif.connect", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: pif.delete id=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: PifDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pif.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: pif.disconnect id=<string>
+    pub async fn disconnect<R: DeserializeOwned>(
+        &self,
+        params: PifDisconnectParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pif.disconnect", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: pif.editPif id=<string> vlan=<integer|string>
+    pub async fn edit_pif<R: DeserializeOwned>(
+        &self,
+        params: PifEditPifParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pif.editPif", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: pif.getIpv4ConfigurationModes
+    pub async fn get_ipv4_configuration_modes<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("pif.getIpv4ConfigurationModes", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// xo-cli: pif.getIpv6ConfigurationModes
+    pub async fn get_ipv6_configuration_modes<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("pif.getIpv6ConfigurationModes", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// xo-cli: pif.reconfigureIp [dns=<string>] [gateway=<string>] [id=<string>] [ip=<string>] [mode=<string>] [netmask=<string>]
+    pub async fn reconfigure_ip<R: DeserializeOwned>(
+        &self,
+        params: PifReconfigureIpParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pif.reconfigureIp", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
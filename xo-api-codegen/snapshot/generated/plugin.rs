@@ -0,0 +1,170 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`PluginProcedures::configure`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PluginConfigureParams {
+    pub configuration: JsonValue,
+    pub id: String,
+}
+
+/// Parameters of [`PluginProcedures::disable_autoload`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PluginDisableAutoloadParams {
+    pub id: String,
+}
+
+/// Parameters of [`PluginProcedures::enable_autoload`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PluginEnableAutoloadParams {
+    pub id: String,
+}
+
+/// Parameters of [`PluginProcedures::load`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PluginLoadParams {
+    pub id: String,
+}
+
+/// Parameters of [`PluginProcedures::purge_configuration`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PluginPurgeConfigurationParams {
+    pub id: String,
+}
+
+/// Parameters of [`PluginProcedures::test`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PluginTestParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<JsonValue>,
+    pub id: String,
+}
+
+/// Parameters of [`PluginProcedures::unload`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PluginUnloadParams {
+    pub id: String,
+}
+
+pub struct PluginProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl PluginProcedures {
+    /// Sets the configuration of a plugin
+    ///
+    /// xo-cli: plugin.configure configuration=<any> id=<string>
+    pub async fn configure<R: DeserializeOwned>(
+        &self,
+        params: PluginConfigureParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("plugin.configure", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: plugin.disableAutoload id=<string>
+    pub async fn disable_autoload<R: DeserializeOwned>(
+        &self,
+        params: PluginDisableAutoloadParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("plugin.disableAutoload", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Enables a plugin, allowing it to be loaded
+    ///
+    /// xo-cli: plugin.enableAutoload id=<string>
+    pub async fn enable_autoload<R: DeserializeOwned>(
+        &self,
+        params: PluginEnableAutoloadParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("plugin.enableAutoload", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Returns a list of all installed plugins
+    ///
+    /// xo-cli: plugin.get
+    pub async fn get<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("plugin.get", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Loads a plugin
+    ///
+    /// xo-cli: plugin.load id=<string>
+    pub async fn load<R: DeserializeOwned>(
+        &self,
+        params: PluginLoadParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("plugin.load", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Removes a plugin configuration
+    ///
+    /// xo-cli: plugin.purgeConfiguration id=<string>
+    pub async fn purge_configuration<R: DeserializeOwned>(
+        &self,
+        params: PluginPurgeConfigurationParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("plugin.purgeConfiguration", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Test a plugin with its current configuration
+    ///
+    /// xo-cli: plugin.test [data=<any>] id=<string>
+    pub async fn test<R: DeserializeOwned>(
+        &self,
+        params: PluginTestParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("plugin.test", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Unloads a plugin
+    ///
+    /// xo-cli: plugin.unload id=<string>
+    pub async fn unload<R: DeserializeOwned>(
+        &self,
+        params: PluginUnloadParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("plugin.unload", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: plugin.usageReport.send
+    pub async fn usage_report_send<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("plugin.usageReport.send", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+}
@@ -0,0 +1,289 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`PoolProcedures::get_license_state`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PoolGetLicenseStateParams {
+    pub pool: String,
+}
+
+/// Parameters of [`PoolProcedures::get_patches_difference`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PoolGetPatchesDifferenceParams {
+    pub source: String,
+    pub target: String,
+}
+
+/// Parameters of [`PoolProcedures::install_patches`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PoolInstallPatchesParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hosts: Option<Vec<JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patches: Option<Vec<JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool: Option<String>,
+}
+
+/// Parameters of [`PoolProcedures::install_supplemental_pack`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PoolInstallSupplementalPackParams {
+    pub pool: String,
+}
+
+/// Parameters of [`PoolProcedures::list_missing_patches`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PoolListMissingPatchesParams {
+    pub host: String,
+}
+
+/// Parameters of [`PoolProcedures::list_pools_matching_criteria`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PoolListPoolsMatchingCriteriaParams {
+    #[serde(rename = "minAvailableHostMemory")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_available_host_memory: Option<f64>,
+    #[serde(rename = "minAvailableSrSize")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_available_sr_size: Option<f64>,
+    #[serde(rename = "minHostCpus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_host_cpus: Option<f64>,
+    #[serde(rename = "minHostVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_host_version: Option<String>,
+    #[serde(rename = "poolNameRegExp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool_name_reg_exp: Option<String>,
+    #[serde(rename = "srNameRegExp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sr_name_reg_exp: Option<String>,
+}
+
+/// Parameters of [`PoolProcedures::merge_into`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PoolMergeIntoParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sources: Option<Vec<JsonValue>>,
+    pub target: String,
+}
+
+/// Parameters of [`PoolProcedures::patch`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PoolPatchParams {
+    pub pool: String,
+}
+
+/// Parameters of [`PoolProcedures::rolling_update`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PoolRollingUpdateParams {
+    pub pool: String,
+}
+
+/// Parameters of [`PoolProcedures::set`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PoolSetParams {
+    pub id: String,
+    #[serde(rename = "migrationNetwork")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migration_network: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_label: Option<String>,
+}
+
+/// Parameters of [`PoolProcedures::set_default_sr`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PoolSetDefaultSrParams {
+    pub sr: String,
+}
+
+/// Parameters of [`PoolProcedures::set_pool_master`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PoolSetPoolMasterParams {
+    pub host: String,
+}
+
+/// Parameters of [`PoolProcedures::upload_patch`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PoolUploadPatchParams {
+    pub pool: String,
+}
+
+pub struct PoolProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl PoolProcedures {
+    /// xo-cli: pool.getLicenseState pool=<string>
+    pub async fn get_license_state<R: DeserializeOwned>(
+        &self,
+        params: PoolGetLicenseStateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pool.getLicenseState", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: pool.getPatchesDifference source=<string> target=<string>
+    pub async fn get_patches_difference<R: DeserializeOwned>(
+        &self,
+        params: PoolGetPatchesDifferenceParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pool.getPatchesDifference", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Install patches on hosts
+    ///
+    /// xo-cli: pool.installPatches [hosts=<array>] [patches=<array>] [pool=<string>]
+    pub async fn install_patches<R: DeserializeOwned>(
+        &self,
+        params: PoolInstallPatchesParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pool.installPatches", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Installs supplemental pack from ISO file on all hosts
+    ///
+    /// xo-cli: pool.installSupplementalPack pool=<string>
+    pub async fn install_supplemental_pack<R: DeserializeOwned>(
+        &self,
+        params: PoolInstallSupplementalPackParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pool.installSupplementalPack", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Return an array of missing new patches in the host
+    ///
+    /// xo-cli: pool.listMissingPatches host=<string>
+    pub async fn list_missing_patches<R: DeserializeOwned>(
+        &self,
+        params: PoolListMissingPatchesParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pool.listMissingPatches", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: pool.listPoolsMatchingCriteria [minAvailableHostMemory=<number>] [minAvailableSrSize=<number>] [minHostCpus=<number>] [minHostVersion=<string>] [poolNameRegExp=<string>] [srNameRegExp=<string>]
+    pub async fn list_pools_matching_criteria<R: DeserializeOwned>(
+        &self,
+        params: PoolListPoolsMatchingCriteriaParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pool.listPoolsMatchingCriteria", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: pool.mergeInto [force=<boolean>] [source=<string>] [sources=<array>] target=<string>
+    pub async fn merge_into<R: DeserializeOwned>(
+        &self,
+        params: PoolMergeIntoParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pool.mergeInto", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: pool.patch pool=<string>
+    pub async fn patch<R: DeserializeOwned>(
+        &self,
+        params: PoolPatchParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pool.patch", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: pool.rollingUpdate pool=<string>
+    pub async fn rolling_update<R: DeserializeOwned>(
+        &self,
+        params: PoolRollingUpdateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pool.rollingUpdate", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: pool.set id=<string> [migrationNetwork=<string|null>] [name_description=<string>] [name_label=<string>]
+    pub async fn set<R: DeserializeOwned>(
+        &self,
+        params: PoolSetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pool.set", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: pool.setDefaultSr sr=<string>
+    pub async fn set_default_sr<R: DeserializeOwned>(
+        &self,
+        params: PoolSetDefaultSrParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pool.setDefaultSr", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: pool.setPoolMaster host=<string>
+    pub async fn set_pool_master<R: DeserializeOwned>(
+        &self,
+        params: PoolSetPoolMasterParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pool.setPoolMaster", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: pool.uploadPatch pool=<string>
+    pub async fn upload_patch<R: DeserializeOwned>(
+        &self,
+        params: PoolUploadPatchParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("pool.uploadPatch", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
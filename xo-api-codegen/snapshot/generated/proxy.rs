@@ -0,0 +1,233 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`ProxyProcedures::check_health`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ProxyCheckHealthParams {
+    pub id: String,
+}
+
+/// Parameters of [`ProxyProcedures::deploy`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ProxyDeployParams {
+    #[serde(rename = "httpProxy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_proxy: Option<String>,
+    pub license: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    #[serde(rename = "networkConfiguration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_configuration: Option<serde_json::Map<String, JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    pub sr: String,
+}
+
+/// Parameters of [`ProxyProcedures::destroy`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ProxyDestroyParams {
+    pub id: String,
+}
+
+/// Parameters of [`ProxyProcedures::get`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ProxyGetParams {
+    pub id: String,
+}
+
+/// Parameters of [`ProxyProcedures::get_appliance_updater_state`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ProxyGetApplianceUpdaterStateParams {
+    pub id: String,
+}
+
+/// Parameters of [`ProxyProcedures::register`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ProxyRegisterParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(rename = "authenticationToken")]
+    pub authentication_token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vm: Option<String>,
+}
+
+/// Parameters of [`ProxyProcedures::unregister`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ProxyUnregisterParams {
+    pub id: String,
+}
+
+/// Parameters of [`ProxyProcedures::update`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ProxyUpdateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Option<String>>,
+    #[serde(rename = "authenticationToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_token: Option<String>,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vm: Option<Option<String>>,
+}
+
+/// Parameters of [`ProxyProcedures::update_appliance_settings`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ProxyUpdateApplianceSettingsParams {
+    #[serde(rename = "httpProxy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_proxy: Option<Option<String>>,
+    pub id: String,
+}
+
+/// Parameters of [`ProxyProcedures::upgrade_appliance`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ProxyUpgradeApplianceParams {
+    pub id: String,
+    #[serde(rename = "ignoreRunningJobs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_running_jobs: Option<bool>,
+}
+
+pub struct ProxyProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl ProxyProcedures {
+    /// xo-cli: proxy.checkHealth id=<string>
+    pub async fn check_health<R: DeserializeOwned>(
+        &self,
+        params: ProxyCheckHealthParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("proxy.checkHealth", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: proxy.deploy [httpProxy=<string>] license=<string> [network=<string>] [networkConfiguration=<object>] [proxy=<string>] sr=<string>
+    pub async fn deploy<R: DeserializeOwned>(
+        &self,
+        params: ProxyDeployParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("proxy.deploy", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: proxy.destroy id=<string>
+    pub async fn destroy<R: DeserializeOwned>(
+        &self,
+        params: ProxyDestroyParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("proxy.destroy", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: proxy.get id=<string>
+    pub async fn get<R: DeserializeOwned>(
+        &self,
+        params: ProxyGetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("proxy.get", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: proxy.getAll
+    pub async fn get_all<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("proxy.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// xo-cli: proxy.getApplianceUpdaterState id=<string>
+    pub async fn get_appliance_updater_state<R: DeserializeOwned>(
+        &self,
+        params: ProxyGetApplianceUpdaterStateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("proxy.getApplianceUpdaterState", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: proxy.register [address=<string>] authenticationToken=<string> [name=<string>] [vm=<string>]
+    pub async fn register<R: DeserializeOwned>(
+        &self,
+        params: ProxyRegisterParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("proxy.register", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: proxy.unregister id=<string>
+    pub async fn unregister<R: DeserializeOwned>(
+        &self,
+        params: ProxyUnregisterParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("proxy.unregister", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: proxy.update [address=<string|null>] [authenticationToken=<string>] id=<string> [name=<string>] [vm=<string|null>]
+    pub async fn update<R: DeserializeOwned>(
+        &self,
+        params: ProxyUpdateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("proxy.update", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: proxy.updateApplianceSettings [httpProxy=<string|null>] id=<string>
+    pub async fn update_appliance_settings<R: DeserializeOwned>(
+        &self,
+        params: ProxyUpdateApplianceSettingsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("proxy.updateApplianceSettings", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: proxy.upgradeAppliance id=<string> [ignoreRunningJobs=<boolean>]
+    pub async fn upgrade_appliance<R: DeserializeOwned>(
+        &self,
+        params: ProxyUpgradeApplianceParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("proxy.upgradeAppliance", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
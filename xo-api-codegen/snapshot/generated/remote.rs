@@ -0,0 +1,146 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`RemoteProcedures::create`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct RemoteCreateParams {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    pub url: String,
+}
+
+/// Parameters of [`RemoteProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct RemoteDeleteParams {
+    pub id: String,
+}
+
+/// Parameters of [`RemoteProcedures::get`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct RemoteGetParams {
+    pub id: String,
+}
+
+/// Parameters of [`RemoteProcedures::set`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct RemoteSetParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Parameters of [`RemoteProcedures::test`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct RemoteTestParams {
+    pub id: String,
+}
+
+pub struct RemoteProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl RemoteProcedures {
+    /// Creates a new fs remote point
+    ///
+    /// xo-cli: remote.create name=<string> [options=<string>] [proxy=<string>] url=<string>
+    pub async fn create<R: DeserializeOwned>(
+        &self,
+        params: RemoteCreateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("remote.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Deletes an existing fs remote point
+    ///
+    /// xo-cli: remote.delete id=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: RemoteDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("remote.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Gets an existing fs remote point
+    ///
+    /// xo-cli: remote.get id=<string>
+    pub async fn get<R: DeserializeOwned>(
+        &self,
+        params: RemoteGetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("remote.get", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Gets all existing fs remote points
+    ///
+    /// xo-cli: remote.getAll
+    pub async fn get_all<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("remote.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Gets all info of remote
+    ///
+    /// xo-cli: remote.getAllInfo
+    pub async fn get_all_info<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("remote.getAllInfo", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Modifies an existing fs remote point
+    ///
+    /// xo-cli: remote.set [enabled=<boolean>] id=<string> [name=<string>] [options=<string|null>] [proxy=<string|null>] [url=<string>]
+    pub async fn set<R: DeserializeOwned>(
+        &self,
+        params: RemoteSetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("remote.set", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Performs a read/write matching test on a remote point
+    ///
+    /// xo-cli: remote.test id=<string>
+    pub async fn test<R: DeserializeOwned>(
+        &self,
+        params: RemoteTestParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("remote.test", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
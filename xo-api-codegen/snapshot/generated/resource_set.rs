@@ -0,0 +1,237 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`ResourceSetProcedures::add_limit`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ResourceSetAddLimitParams {
+    pub id: String,
+    #[serde(rename = "limitId")]
+    pub limit_id: String,
+    pub quantity: i64,
+}
+
+/// Parameters of [`ResourceSetProcedures::add_object`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ResourceSetAddObjectParams {
+    pub id: String,
+    pub object: String,
+}
+
+/// Parameters of [`ResourceSetProcedures::add_subject`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ResourceSetAddSubjectParams {
+    pub id: String,
+    pub subject: String,
+}
+
+/// Parameters of [`ResourceSetProcedures::create`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ResourceSetCreateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limits: Option<serde_json::Map<String, JsonValue>>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub objects: Option<Vec<JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subjects: Option<Vec<JsonValue>>,
+}
+
+/// Parameters of [`ResourceSetProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ResourceSetDeleteParams {
+    pub id: String,
+}
+
+/// Parameters of [`ResourceSetProcedures::get`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ResourceSetGetParams {
+    pub id: String,
+}
+
+/// Parameters of [`ResourceSetProcedures::remove_limit`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ResourceSetRemoveLimitParams {
+    pub id: String,
+    #[serde(rename = "limitId")]
+    pub limit_id: String,
+}
+
+/// Parameters of [`ResourceSetProcedures::remove_object`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ResourceSetRemoveObjectParams {
+    pub id: String,
+    pub object: String,
+}
+
+/// Parameters of [`ResourceSetProcedures::remove_subject`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ResourceSetRemoveSubjectParams {
+    pub id: String,
+    pub subject: String,
+}
+
+/// Parameters of [`ResourceSetProcedures::set`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ResourceSetSetParams {
+    pub id: String,
+    #[serde(rename = "ipPools")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_pools: Option<Vec<JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limits: Option<serde_json::Map<String, JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub objects: Option<Vec<JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subjects: Option<Vec<JsonValue>>,
+}
+
+pub struct ResourceSetProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl ResourceSetProcedures {
+    /// xo-cli: resourceSet.addLimit id=<string> limitId=<string> quantity=<integer>
+    pub async fn add_limit<R: DeserializeOwned>(
+        &self,
+        params: ResourceSetAddLimitParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("resourceSet.addLimit", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: resourceSet.addObject id=<string> object=<string>
+    pub async fn add_object<R: DeserializeOwned>(
+        &self,
+        params: ResourceSetAddObjectParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("resourceSet.addObject", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: resourceSet.addSubject id=<string> subject=<string>
+    pub async fn add_subject<R: DeserializeOwned>(
+        &self,
+        params: ResourceSetAddSubjectParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("resourceSet.addSubject", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: resourceSet.create [limits=<object>] name=<string> [objects=<array>] [subjects=<array>]
+    pub async fn create<R: DeserializeOwned>(
+        &self,
+        params: ResourceSetCreateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("resourceSet.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: resourceSet.delete id=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: ResourceSetDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("resourceSet.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: resourceSet.get id=<string>
+    pub async fn get<R: DeserializeOwned>(
+        &self,
+        params: ResourceSetGetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("resourceSet.get", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Get the list of all existing resource set
+    ///
+    /// xo-cli: resourceSet.getAll
+    pub async fn get_all<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("resourceSet.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Recompute manually the current resource set usage
+    ///
+    /// xo-cli: resourceSet.recomputeAllLimits
+    pub async fn recompute_all_limits<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("resourceSet.recomputeAllLimits", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// xo-cli: resourceSet.removeLimit id=<string> limitId=<string>
+    pub async fn remove_limit<R: DeserializeOwned>(
+        &self,
+        params: ResourceSetRemoveLimitParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("resourceSet.removeLimit", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: resourceSet.removeObject id=<string> object=<string>
+    pub async fn remove_object<R: DeserializeOwned>(
+        &self,
+        params: ResourceSetRemoveObjectParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("resourceSet.removeObject", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: resourceSet.removeSubject id=<string> subject=<string>
+    pub async fn remove_subject<R: DeserializeOwned>(
+        &self,
+        params: ResourceSetRemoveSubjectParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("resourceSet.removeSubject", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: resourceSet.set id=<string> [ipPools=<array>] [limits=<object>] [name=<string>] [objects=<array>] [subjects=<array>]
+    pub async fn set<R: DeserializeOwned>(
+        &self,
+        params: ResourceSetSetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("resourceSet.set", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
@@ -0,0 +1,22 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+pub struct RoleProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl RoleProcedures {
+    /// Returns the list of all existing roles
+    ///
+    /// xo-cli: role.getAll
+    pub async fn get_all<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("role.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+}
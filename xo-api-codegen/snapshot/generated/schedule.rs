@@ -0,0 +1,121 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`ScheduleProcedures::create`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ScheduleCreateParams {
+    pub cron: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(rename = "jobId")]
+    pub job_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+/// Parameters of [`ScheduleProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ScheduleDeleteParams {
+    pub id: String,
+}
+
+/// Parameters of [`ScheduleProcedures::get`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ScheduleGetParams {
+    pub id: String,
+}
+
+/// Parameters of [`ScheduleProcedures::set`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ScheduleSetParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cron: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    pub id: String,
+    #[serde(rename = "jobId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+pub struct ScheduleProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl ScheduleProcedures {
+    /// Creates a new schedule
+    ///
+    /// xo-cli: schedule.create cron=<string> [enabled=<boolean>] jobId=<string> [name=<string>] [timezone=<string>]
+    pub async fn create<R: DeserializeOwned>(
+        &self,
+        params: ScheduleCreateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("schedule.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Deletes an existing schedule
+    ///
+    /// xo-cli: schedule.delete id=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: ScheduleDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("schedule.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Gets an existing schedule
+    ///
+    /// xo-cli: schedule.get id=<string>
+    pub async fn get<R: DeserializeOwned>(
+        &self,
+        params: ScheduleGetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("schedule.get", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Gets all existing schedules
+    ///
+    /// xo-cli: schedule.getAll
+    pub async fn get_all<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("schedule.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Modifies an existing schedule
+    ///
+    /// xo-cli: schedule.set [cron=<string>] [enabled=<boolean>] id=<string> [jobId=<string>] [name=<string|null>] [timezone=<string>]
+    pub async fn set<R: DeserializeOwned>(
+        &self,
+        params: ScheduleSetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("schedule.set", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
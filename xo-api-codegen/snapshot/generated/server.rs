@@ -0,0 +1,146 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`ServerProcedures::add`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ServerAddParams {
+    #[serde(rename = "allowUnauthorized")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_unauthorized: Option<bool>,
+    #[serde(rename = "autoConnect")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_connect: Option<bool>,
+    pub host: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    pub password: String,
+    pub username: String,
+}
+
+/// Parameters of [`ServerProcedures::disable`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ServerDisableParams {
+    pub id: String,
+}
+
+/// Parameters of [`ServerProcedures::enable`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ServerEnableParams {
+    pub id: String,
+}
+
+/// Parameters of [`ServerProcedures::remove`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ServerRemoveParams {
+    pub id: String,
+}
+
+/// Parameters of [`ServerProcedures::set`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ServerSetParams {
+    #[serde(rename = "allowUnauthorized")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_unauthorized: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(rename = "readOnly")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
+
+pub struct ServerProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl ServerProcedures {
+    /// Register a new Xen server
+    ///
+    /// xo-cli: server.add [allowUnauthorized=<boolean>] [autoConnect=<boolean>] host=<string> [label=<string>] password=<string> username=<string>
+    pub async fn add<R: DeserializeOwned>(
+        &self,
+        params: ServerAddParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("server.add", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Disable a Xen server
+    ///
+    /// xo-cli: server.disable id=<string>
+    pub async fn disable<R: DeserializeOwned>(
+        &self,
+        params: ServerDisableParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("server.disable", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Enable a Xen server
+    ///
+    /// xo-cli: server.enable id=<string>
+    pub async fn enable<R: DeserializeOwned>(
+        &self,
+        params: ServerEnableParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("server.enable", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Returns all the registered Xen server
+    ///
+    /// xo-cli: server.getAll
+    pub async fn get_all<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("server.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Unregister a Xen server
+    ///
+    /// xo-cli: server.remove id=<string>
+    pub async fn remove<R: DeserializeOwned>(
+        &self,
+        params: ServerRemoveParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("server.remove", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Changes the properties of a Xen server
+    ///
+    /// xo-cli: server.set [allowUnauthorized=<boolean>] [host=<string>] id=<string> [label=<string>] [password=<string>] [readOnly=<boolean>] [username=<string>]
+    pub async fn set<R: DeserializeOwned>(
+        &self,
+        params: ServerSetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("server.set", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
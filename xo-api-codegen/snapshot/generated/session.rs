@@ -0,0 +1,81 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`SessionProcedures::sign_in_with_password`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SessionSignInWithPasswordParams {
+    pub email: String,
+    pub password: String,
+}
+
+/// Parameters of [`SessionProcedures::sign_in_with_token`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SessionSignInWithTokenParams {
+    pub token: String,
+}
+
+pub struct SessionProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl SessionProcedures {
+    /// Return the currently connected user
+    ///
+    /// xo-cli: session.getUser
+    pub async fn get_user<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("session.getUser", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Sign in
+    ///
+    /// xo-cli: session.signIn
+    pub async fn sign_in<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("session.signIn", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Sign in
+    ///
+    /// xo-cli: session.signInWithPassword email=<string> password=<string>
+    pub async fn sign_in_with_password<R: DeserializeOwned>(
+        &self,
+        params: SessionSignInWithPasswordParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("session.signInWithPassword", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Sign in
+    ///
+    /// xo-cli: session.signInWithToken token=<string>
+    pub async fn sign_in_with_token<R: DeserializeOwned>(
+        &self,
+        params: SessionSignInWithTokenParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("session.signInWithToken", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Sign out the user from the current session
+    ///
+    /// xo-cli: session.signOut
+    pub async fn sign_out<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("session.signOut", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+}
@@ -0,0 +1,547 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`SrProcedures::connect_all_pbds`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrConnectAllPbdsParams {
+    pub id: String,
+}
+
+/// Parameters of [`SrProcedures::create_ext`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrCreateExtParams {
+    pub device: String,
+    pub host: String,
+    #[serde(rename = "nameDescription")]
+    pub name_description: String,
+    #[serde(rename = "nameLabel")]
+    pub name_label: String,
+}
+
+/// Parameters of [`SrProcedures::create_hba`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrCreateHbaParams {
+    pub host: String,
+    #[serde(rename = "nameDescription")]
+    pub name_description: String,
+    #[serde(rename = "nameLabel")]
+    pub name_label: String,
+    #[serde(rename = "scsiId")]
+    pub scsi_id: String,
+    #[serde(rename = "srUuid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sr_uuid: Option<String>,
+}
+
+/// Parameters of [`SrProcedures::create_iscsi`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrCreateIscsiParams {
+    #[serde(rename = "chapPassword")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chap_password: Option<String>,
+    #[serde(rename = "chapUser")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chap_user: Option<String>,
+    pub host: String,
+    #[serde(rename = "nameDescription")]
+    pub name_description: String,
+    #[serde(rename = "nameLabel")]
+    pub name_label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<i64>,
+    #[serde(rename = "scsiId")]
+    pub scsi_id: String,
+    #[serde(rename = "srUuid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sr_uuid: Option<String>,
+    pub target: String,
+    #[serde(rename = "targetIqn")]
+    pub target_iqn: String,
+}
+
+/// Parameters of [`SrProcedures::create_iso`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrCreateIsoParams {
+    pub host: String,
+    #[serde(rename = "nameDescription")]
+    pub name_description: String,
+    #[serde(rename = "nameLabel")]
+    pub name_label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    pub path: String,
+    #[serde(rename = "srUuid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sr_uuid: Option<String>,
+    pub r#type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+/// Parameters of [`SrProcedures::create_lvm`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrCreateLvmParams {
+    pub device: String,
+    pub host: String,
+    #[serde(rename = "nameDescription")]
+    pub name_description: String,
+    #[serde(rename = "nameLabel")]
+    pub name_label: String,
+}
+
+/// Parameters of [`SrProcedures::create_nfs`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrCreateNfsParams {
+    pub host: String,
+    #[serde(rename = "nameDescription")]
+    pub name_description: String,
+    #[serde(rename = "nameLabel")]
+    pub name_label: String,
+    #[serde(rename = "nfsOptions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nfs_options: Option<String>,
+    #[serde(rename = "nfsVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nfs_version: Option<String>,
+    pub server: String,
+    #[serde(rename = "serverPath")]
+    pub server_path: String,
+    #[serde(rename = "srUuid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sr_uuid: Option<String>,
+}
+
+/// Parameters of [`SrProcedures::create_zfs`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrCreateZfsParams {
+    pub host: String,
+    pub location: String,
+    #[serde(rename = "nameDescription")]
+    pub name_description: String,
+    #[serde(rename = "nameLabel")]
+    pub name_label: String,
+}
+
+/// Parameters of [`SrProcedures::destroy`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrDestroyParams {
+    pub id: String,
+}
+
+/// Parameters of [`SrProcedures::disconnect_all_pbds`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrDisconnectAllPbdsParams {
+    pub id: String,
+}
+
+/// Parameters of [`SrProcedures::forget`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrForgetParams {
+    pub id: String,
+}
+
+/// Parameters of [`SrProcedures::get_unhealthy_vdi_chains_length`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrGetUnhealthyVdiChainsLengthParams {
+    pub id: String,
+}
+
+/// Parameters of [`SrProcedures::probe_hba`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrProbeHbaParams {
+    pub host: String,
+}
+
+/// Parameters of [`SrProcedures::probe_hba_exists`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrProbeHbaExistsParams {
+    pub host: String,
+    #[serde(rename = "scsiId")]
+    pub scsi_id: String,
+}
+
+/// Parameters of [`SrProcedures::probe_iscsi_exists`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrProbeIscsiExistsParams {
+    #[serde(rename = "chapPassword")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chap_password: Option<String>,
+    #[serde(rename = "chapUser")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chap_user: Option<String>,
+    pub host: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<i64>,
+    #[serde(rename = "scsiId")]
+    pub scsi_id: String,
+    pub target: String,
+    #[serde(rename = "targetIqn")]
+    pub target_iqn: String,
+}
+
+/// Parameters of [`SrProcedures::probe_iscsi_iqns`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrProbeIscsiIqnsParams {
+    #[serde(rename = "chapPassword")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chap_password: Option<String>,
+    #[serde(rename = "chapUser")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chap_user: Option<String>,
+    pub host: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<i64>,
+    pub target: String,
+}
+
+/// Parameters of [`SrProcedures::probe_iscsi_luns`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrProbeIscsiLunsParams {
+    #[serde(rename = "chapPassword")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chap_password: Option<String>,
+    #[serde(rename = "chapUser")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chap_user: Option<String>,
+    pub host: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<i64>,
+    pub target: String,
+    #[serde(rename = "targetIqn")]
+    pub target_iqn: String,
+}
+
+/// Parameters of [`SrProcedures::probe_nfs`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrProbeNfsParams {
+    pub host: String,
+    pub server: String,
+}
+
+/// Parameters of [`SrProcedures::probe_nfs_exists`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrProbeNfsExistsParams {
+    pub host: String,
+    pub server: String,
+    #[serde(rename = "serverPath")]
+    pub server_path: String,
+}
+
+/// Parameters of [`SrProcedures::probe_zfs`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrProbeZfsParams {
+    pub host: String,
+}
+
+/// Parameters of [`SrProcedures::scan`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrScanParams {
+    pub id: String,
+}
+
+/// Parameters of [`SrProcedures::set`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrSetParams {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_label: Option<String>,
+}
+
+/// Parameters of [`SrProcedures::stats`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SrStatsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granularity: Option<String>,
+    pub id: String,
+}
+
+pub struct SrProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl SrProcedures {
+    /// xo-cli: sr.connectAllPbds id=<string>
+    pub async fn connect_all_pbds<R: DeserializeOwned>(
+        &self,
+        params: SrConnectAllPbdsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.connectAllPbds", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.createExt device=<string> host=<string> nameDescription=<string> nameLabel=<string>
+    pub async fn create_ext<R: DeserializeOwned>(
+        &self,
+        params: SrCreateExtParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.createExt", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.createHba host=<string> nameDescription=<string> nameLabel=<string> scsiId=<string> [srUuid=<string>]
+    pub async fn create_hba<R: DeserializeOwned>(
+        &self,
+        params: SrCreateHbaParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.createHba", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.createIscsi [chapPassword=<string>] [chapUser=<string>] host=<string> nameDescription=<string> nameLabel=<string> [port=<integer>] scsiId=<string> [srUuid=<string>] target=<string> targetIqn=<string>
+    pub async fn create_iscsi<R: DeserializeOwned>(
+        &self,
+        params: SrCreateIscsiParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.createIscsi", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.createIso host=<string> nameDescription=<string> nameLabel=<string> [password=<string>] path=<string> [srUuid=<string>] type=<string> [user=<string>]
+    pub async fn create_iso<R: DeserializeOwned>(
+        &self,
+        params: SrCreateIsoParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.createIso", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.createLvm device=<string> host=<string> nameDescription=<string> nameLabel=<string>
+    pub async fn create_lvm<R: DeserializeOwned>(
+        &self,
+        params: SrCreateLvmParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.createLvm", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.createNfs host=<string> nameDescription=<string> nameLabel=<string> [nfsOptions=<string>] [nfsVersion=<string>] server=<string> serverPath=<string> [srUuid=<string>]
+    pub async fn create_nfs<R: DeserializeOwned>(
+        &self,
+        params: SrCreateNfsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.createNfs", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.createZfs host=<string> location=<string> nameDescription=<string> nameLabel=<string>
+    pub async fn create_zfs<R: DeserializeOwned>(
+        &self,
+        params: SrCreateZfsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.createZfs", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.destroy id=<string>
+    pub async fn destroy<R: DeserializeOwned>(
+        &self,
+        params: SrDestroyParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.destroy", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.disconnectAllPbds id=<string>
+    pub async fn disconnect_all_pbds<R: DeserializeOwned>(
+        &self,
+        params: SrDisconnectAllPbdsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.disconnectAllPbds", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.forget id=<string>
+    pub async fn forget<R: DeserializeOwned>(
+        &self,
+        params: SrForgetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.forget", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.getUnhealthyVdiChainsLength id=<string>
+    pub async fn get_unhealthy_vdi_chains_length<R: DeserializeOwned>(
+        &self,
+        params: SrGetUnhealthyVdiChainsLengthParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.getUnhealthyVdiChainsLength", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.probeHba host=<string>
+    pub async fn probe_hba<R: DeserializeOwned>(
+        &self,
+        params: SrProbeHbaParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.probeHba", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.probeHbaExists host=<string> scsiId=<string>
+    pub async fn probe_hba_exists<R: DeserializeOwned>(
+        &self,
+        params: SrProbeHbaExistsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.probeHbaExists", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.probeIscsiExists [chapPassword=<string>] [chapUser=<string>] host=<string> [port=<integer>] scsiId=<string> target=<string> targetIqn=<string>
+    pub async fn probe_iscsi_exists<R: DeserializeOwned>(
+        &self,
+        params: SrProbeIscsiExistsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.probeIscsiExists", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.probeIscsiIqns [chapPassword=<string>] [chapUser=<string>] host=<string> [port=<integer>] target=<string>
+    pub async fn probe_iscsi_iqns<R: DeserializeOwned>(
+        &self,
+        params: SrProbeIscsiIqnsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.probeIscsiIqns", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.probeIscsiLuns [chapPassword=<string>] [chapUser=<string>] host=<string> [port=<integer>] target=<string> targetIqn=<string>
+    pub async fn probe_iscsi_luns<R: DeserializeOwned>(
+        &self,
+        params: SrProbeIscsiLunsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.probeIscsiLuns", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.probeNfs host=<string> server=<string>
+    pub async fn probe_nfs<R: DeserializeOwned>(
+        &self,
+        params: SrProbeNfsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.probeNfs", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.probeNfsExists host=<string> server=<string> serverPath=<string>
+    pub async fn probe_nfs_exists<R: DeserializeOwned>(
+        &self,
+        params: SrProbeNfsExistsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.probeNfsExists", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.probeZfs host=<string>
+    pub async fn probe_zfs<R: DeserializeOwned>(
+        &self,
+        params: SrProbeZfsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.probeZfs", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.scan id=<string>
+    pub async fn scan<R: DeserializeOwned>(
+        &self,
+        params: SrScanParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.scan", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: sr.set id=<string> [name_description=<string>] [name_label=<string>]
+    pub async fn set<R: DeserializeOwned>(
+        &self,
+        params: SrSetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.set", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Returns statistic of the sr
+    ///
+    /// xo-cli: sr.stats [granularity=<string>] id=<string>
+    pub async fn stats<R: DeserializeOwned>(
+        &self,
+        params: SrStatsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("sr.stats", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
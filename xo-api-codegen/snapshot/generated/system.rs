@@ -0,0 +1,67 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+pub struct SystemProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl SystemProcedures {
+    /// Returns the signatures of all available API methods
+    ///
+    /// xo-cli: system.getMethodsInfo
+    pub async fn get_methods_info<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("system.getMethodsInfo", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Return the timezone server
+    ///
+    /// xo-cli: system.getServerTimezone
+    pub async fn get_server_timezone<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("system.getServerTimezone", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Return the version of xo-server
+    ///
+    /// xo-cli: system.getServerVersion
+    pub async fn get_server_version<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("system.getServerVersion", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// API version (unstable)
+    ///
+    /// xo-cli: system.getVersion
+    pub async fn get_version<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("system.getVersion", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Returns the name of all available API methods
+    ///
+    /// xo-cli: system.listMethods
+    pub async fn list_methods<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("system.listMethods", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Returns the signature of an API method
+    ///
+    /// xo-cli: system.methodSignature
+    pub async fn method_signature<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("system.methodSignature", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+}
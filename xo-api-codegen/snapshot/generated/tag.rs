@@ -0,0 +1,55 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`TagProcedures::add`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct TagAddParams {
+    pub id: String,
+    pub tag: String,
+}
+
+/// Parameters of [`TagProcedures::remove`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct TagRemoveParams {
+    pub id: String,
+    pub tag: String,
+}
+
+pub struct TagProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl TagProcedures {
+    /// Add a new tag to an object
+    ///
+    /// xo-cli: tag.add id=<string> tag=<string>
+    pub async fn add<R: DeserializeOwned>(
+        &self,
+        params: TagAddParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("tag.add", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Remove an existing tag from an object
+    ///
+    /// xo-cli: tag.remove id=<string> tag=<string>
+    pub async fn remove<R: DeserializeOwned>(
+        &self,
+        params: TagRemoveParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("tag.remove", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
@@ -0,0 +1,49 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`TaskProcedures::cancel`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct TaskCancelParams {
+    pub id: String,
+}
+
+/// Parameters of [`TaskProcedures::destroy`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct TaskDestroyParams {
+    pub id: String,
+}
+
+pub struct TaskProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl TaskProcedures {
+    /// xo-cli: task.cancel id=<string>
+    pub async fn cancel<R: DeserializeOwned>(
+        &self,
+        params: TaskCancelParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("task.cancel", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: task.destroy id=<string>
+    pub async fn destroy<R: DeserializeOwned>(
+        &self,
+        params: TaskDestroyParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("task.destroy", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
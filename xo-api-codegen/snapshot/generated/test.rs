@@ -0,0 +1,117 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`TestProcedures::change_connected_xapi_hostname`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct TestChangeConnectedXapiHostnameParams {
+    pub hostname: String,
+    #[serde(rename = "newObject")]
+    pub new_object: String,
+    #[serde(rename = "oldObject")]
+    pub old_object: String,
+}
+
+/// Parameters of [`TestProcedures::copy_vm`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct TestCopyVmParams {
+    pub sr: String,
+    pub vm: String,
+}
+
+/// Parameters of [`TestProcedures::get_permissions_for_user`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct TestGetPermissionsForUserParams {
+    #[serde(rename = "userId")]
+    pub user_id: String,
+}
+
+/// Parameters of [`TestProcedures::has_permission`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct TestHasPermissionParams {
+    #[serde(rename = "objectId")]
+    pub object_id: String,
+    pub permission: String,
+    #[serde(rename = "userId")]
+    pub user_id: String,
+}
+
+/// Parameters of [`TestProcedures::wait`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct TestWaitParams {
+    pub duration: String,
+}
+
+pub struct TestProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl TestProcedures {
+    /// Change the connected XAPI hostname and check if the pool and the local cache are updated
+    ///
+    /// xo-cli: test.changeConnectedXapiHostname hostname=<string> newObject=<string> oldObject=<string>
+    pub async fn change_connected_xapi_hostname<R: DeserializeOwned>(
+        &self,
+        params: TestChangeConnectedXapiHostnameParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("test.changeConnectedXapiHostname", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Export/import full/delta VM
+    ///
+    /// xo-cli: test.copyVm sr=<string> vm=<string>
+    pub async fn copy_vm<R: DeserializeOwned>(
+        &self,
+        params: TestCopyVmParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("test.copyVm", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: test.getPermissionsForUser userId=<string>
+    pub async fn get_permissions_for_user<R: DeserializeOwned>(
+        &self,
+        params: TestGetPermissionsForUserParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("test.getPermissionsForUser", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: test.hasPermission objectId=<string> permission=<string> userId=<string>
+    pub async fn has_permission<R: DeserializeOwned>(
+        &self,
+        params: TestHasPermissionParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("test.hasPermission", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: test.wait duration=<string>
+    pub async fn wait<R: DeserializeOwned>(
+        &self,
+        params: TestWaitParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("test.wait", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
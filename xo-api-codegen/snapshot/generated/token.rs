@@ -0,0 +1,76 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`TokenProcedures::create`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct TokenCreateParams {
+    #[serde(rename = "expiresIn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_in: Option<JsonValue>,
+}
+
+/// Parameters of [`TokenProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct TokenDeleteParams {
+    pub token: String,
+}
+
+/// Parameters of [`TokenProcedures::delete_all`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct TokenDeleteAllParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub except: Option<String>,
+}
+
+pub struct TokenProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl TokenProcedures {
+    /// Create a new authentication token
+    ///
+    /// xo-cli: token.create [expiresIn=<number|string>]
+    pub async fn create<R: DeserializeOwned>(
+        &self,
+        params: TokenCreateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("token.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Delete an existing authentication token
+    ///
+    /// xo-cli: token.delete token=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: TokenDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("token.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Delete all tokens of the current user except the current one
+    ///
+    /// xo-cli: token.deleteAll [except=<string>]
+    pub async fn delete_all<R: DeserializeOwned>(
+        &self,
+        params: TokenDeleteAllParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("token.deleteAll", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
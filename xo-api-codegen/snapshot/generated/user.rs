@@ -0,0 +1,116 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`UserProcedures::change_password`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct UserChangePasswordParams {
+    #[serde(rename = "newPassword")]
+    pub new_password: String,
+    #[serde(rename = "oldPassword")]
+    pub old_password: String,
+}
+
+/// Parameters of [`UserProcedures::create`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct UserCreateParams {
+    pub email: String,
+    pub password: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission: Option<String>,
+}
+
+/// Parameters of [`UserProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct UserDeleteParams {
+    pub id: String,
+}
+
+/// Parameters of [`UserProcedures::set`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct UserSetParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferences: Option<serde_json::Map<String, JsonValue>>,
+}
+
+pub struct UserProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl UserProcedures {
+    /// Change password after checking old password (user function)
+    ///
+    /// xo-cli: user.changePassword newPassword=<string> oldPassword=<string>
+    pub async fn change_password<R: DeserializeOwned>(
+        &self,
+        params: UserChangePasswordParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("user.changePassword", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Creates a new user
+    ///
+    /// xo-cli: user.create email=<string> password=<string> [permission=<string>]
+    pub async fn create<R: DeserializeOwned>(
+        &self,
+        params: UserCreateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("user.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Deletes an existing user
+    ///
+    /// xo-cli: user.delete id=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: UserDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("user.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Returns all the existing users
+    ///
+    /// xo-cli: user.getAll
+    pub async fn get_all<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("user.getAll", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// Changes the properties of an existing user
+    ///
+    /// xo-cli: user.set [email=<string>] id=<string> [password=<string>] [permission=<string>] [preferences=<object>]
+    pub async fn set<R: DeserializeOwned>(
+        &self,
+        params: UserSetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("user.set", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
@@ -0,0 +1,106 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`VbdProcedures::connect`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VbdConnectParams {
+    pub id: String,
+}
+
+/// Parameters of [`VbdProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VbdDeleteParams {
+    pub id: String,
+}
+
+/// Parameters of [`VbdProcedures::disconnect`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VbdDisconnectParams {
+    pub id: String,
+}
+
+/// Parameters of [`VbdProcedures::set`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VbdSetParams {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<JsonValue>,
+}
+
+/// Parameters of [`VbdProcedures::set_bootable`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VbdSetBootableParams {
+    pub bootable: bool,
+    pub vbd: String,
+}
+
+pub struct VbdProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl VbdProcedures {
+    /// xo-cli: vbd.connect id=<string>
+    pub async fn connect<R: DeserializeOwned>(
+        &self,
+        params: VbdConnectParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vbd.connect", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vbd.delete id=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: VbdDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vbd.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vbd.disconnect id=<string>
+    pub async fn disconnect<R: DeserializeOwned>(
+        &self,
+        params: VbdDisconnectParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vbd.disconnect", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vbd.set id=<string> [position=<string|number>]
+    pub async fn set<R: DeserializeOwned>(
+        &self,
+        params: VbdSetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vbd.set", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vbd.setBootable bootable=<boolean> vbd=<string>
+    pub async fn set_bootable<R: DeserializeOwned>(
+        &self,
+        params: VbdSetBootableParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vbd.setBootable", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
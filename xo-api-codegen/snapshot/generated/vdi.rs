@@ -0,0 +1,95 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`VdiProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VdiDeleteParams {
+    pub id: String,
+}
+
+/// Parameters of [`VdiProcedures::delete2`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VdiDelete2Params {
+    pub id: String,
+}
+
+/// Parameters of [`VdiProcedures::migrate`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VdiMigrateParams {
+    pub id: String,
+    #[serde(rename = "resourceSet")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_set: Option<String>,
+    pub sr_id: String,
+}
+
+/// Parameters of [`VdiProcedures::set`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VdiSetParams {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<JsonValue>,
+}
+
+pub struct VdiProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl VdiProcedures {
+    /// xo-cli: vdi.delete id=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: VdiDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vdi.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vdi.delete_ id=<string>
+    pub async fn delete2<R: DeserializeOwned>(
+        &self,
+        params: VdiDelete2Params,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vdi.delete_", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vdi.migrate id=<string> [resourceSet=<string>] sr_id=<string>
+    pub async fn migrate<R: DeserializeOwned>(
+        &self,
+        params: VdiMigrateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vdi.migrate", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vdi.set id=<string> [name_description=<string>] [name_label=<string>] [size=<integer|string>]
+    pub async fn set<R: DeserializeOwned>(
+        &self,
+        params: VdiSetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vdi.set", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
@@ -0,0 +1,116 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`VifProcedures::connect`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VifConnectParams {
+    pub id: String,
+}
+
+/// Parameters of [`VifProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VifDeleteParams {
+    pub id: String,
+}
+
+/// Parameters of [`VifProcedures::disconnect`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VifDisconnectParams {
+    pub id: String,
+}
+
+/// Parameters of [`VifProcedures::set`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VifSetParams {
+    #[serde(rename = "allowedIpv4Addresses")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_ipv4_addresses: Option<Vec<JsonValue>>,
+    #[serde(rename = "allowedIpv6Addresses")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_ipv6_addresses: Option<Vec<JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attached: Option<bool>,
+    pub id: String,
+    #[serde(rename = "lockingMode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locking_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mac: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    #[serde(rename = "rateLimit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<Option<f64>>,
+    #[serde(rename = "resourceSet")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_set: Option<String>,
+    #[serde(rename = "txChecksumming")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_checksumming: Option<bool>,
+}
+
+pub struct VifProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl VifProcedures {
+    /// xo-cli: vif.connect id=<string>
+    pub async fn connect<R: DeserializeOwned>(
+        &self,
+        params: VifConnectParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vif.connect", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vif.delete id=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: VifDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vif.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vif.disconnect id=<string>
+    pub async fn disconnect<R: DeserializeOwned>(
+        &self,
+        params: VifDisconnectParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vif.disconnect", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vif.getLockingModeValues
+    pub async fn get_locking_mode_values<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("vif.getLockingModeValues", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// xo-cli: vif.set [allowedIpv4Addresses=<array>] [allowedIpv6Addresses=<array>] [attached=<boolean>] id=<string> [lockingMode=<string>] [mac=<string>] [network=<string>] [rateLimit=<number|null>] [resourceSet=<string>] [txChecksumming=<boolean>]
+    pub async fn set<R: DeserializeOwned>(
+        &self,
+        params: VifSetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vif.set", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
@@ -0,0 +1,1022 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::RpcError;
+
+/// Parameters of [`VmProcedures::attach_disk`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmAttachDiskParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bootable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<String>,
+    pub vdi: String,
+    pub vm: String,
+}
+
+/// Parameters of [`VmProcedures::attach_pci`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmAttachPciParams {
+    #[serde(rename = "pciId")]
+    pub pci_id: String,
+    pub vm: String,
+}
+
+/// Parameters of [`VmProcedures::backup`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmBackupParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compress: Option<bool>,
+    pub file: String,
+    pub id: String,
+    #[serde(rename = "remoteId")]
+    pub remote_id: String,
+}
+
+/// Parameters of [`VmProcedures::clone`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmCloneParams {
+    pub full_copy: bool,
+    pub id: String,
+    pub name: String,
+}
+
+/// Parameters of [`VmProcedures::convert`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmConvertParams {
+    pub id: String,
+}
+
+/// Parameters of [`VmProcedures::convert_to_template`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmConvertToTemplateParams {
+    pub id: String,
+}
+
+/// Parameters of [`VmProcedures::copy`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmCopyParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compress: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub sr: String,
+    pub vm: String,
+}
+
+/// Parameters of [`VmProcedures::copy_to_template`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmCopyToTemplateParams {
+    pub id: String,
+}
+
+/// Parameters of [`VmProcedures::create`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmCreateParams {
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, JsonValue>,
+    #[serde(rename = "VDIs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vdis: Option<Vec<JsonValue>>,
+    #[serde(rename = "VIFs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vifs: Option<Vec<JsonValue>>,
+    #[serde(rename = "affinityHost")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affinity_host: Option<String>,
+    #[serde(rename = "bootAfterCreate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boot_after_create: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clone: Option<bool>,
+    #[serde(rename = "cloudConfig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud_config: Option<String>,
+    #[serde(rename = "copyHostBiosStrings")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub copy_host_bios_strings: Option<bool>,
+    #[serde(rename = "coreOs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub core_os: Option<bool>,
+    #[serde(rename = "coresPerSocket")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cores_per_socket: Option<JsonValue>,
+    #[serde(rename = "existingDisks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub existing_disks: Option<serde_json::Map<String, JsonValue>>,
+    #[serde(rename = "gpuGroup")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gpu_group: Option<String>,
+    #[serde(rename = "hvmBootFirmware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hvm_boot_firmware: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installation: Option<serde_json::Map<String, JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_description: Option<String>,
+    pub name_label: String,
+    #[serde(rename = "networkConfig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_config: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pv_args: Option<String>,
+    #[serde(rename = "resourceSet")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_set: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share: Option<bool>,
+    pub template: String,
+    #[serde(rename = "vgpuType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vgpu_type: Option<String>,
+}
+
+/// Parameters of [`VmProcedures::create_cloud_init_config_drive`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmCreateCloudInitConfigDriveParams {
+    pub config: String,
+    #[serde(rename = "networkConfig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_config: Option<String>,
+    pub sr: String,
+    pub vm: String,
+}
+
+/// Parameters of [`VmProcedures::create_interface`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmCreateInterfaceParams {
+    #[serde(rename = "allowedIpv4Addresses")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_ipv4_addresses: Option<Vec<JsonValue>>,
+    #[serde(rename = "allowedIpv6Addresses")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_ipv6_addresses: Option<Vec<JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mac: Option<String>,
+    pub network: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<JsonValue>,
+    pub vm: String,
+}
+
+/// Parameters of [`VmProcedures::create_vgpu`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmCreateVgpuParams {
+    #[serde(rename = "gpuGroup")]
+    pub gpu_group: String,
+    #[serde(rename = "vgpuType")]
+    pub vgpu_type: String,
+    pub vm: String,
+}
+
+/// Parameters of [`VmProcedures::delete`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmDeleteParams {
+    #[serde(rename = "deleteDisks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_disks: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    #[serde(rename = "forceDeleteDefaultTemplate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force_delete_default_template: Option<bool>,
+    pub id: String,
+}
+
+/// Parameters of [`VmProcedures::delete_vgpu`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmDeleteVgpuParams {
+    pub vgpu: String,
+}
+
+/// Parameters of [`VmProcedures::delta_copy`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmDeltaCopyParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention: Option<f64>,
+    pub sr: String,
+}
+
+/// Parameters of [`VmProcedures::detach_pci`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmDetachPciParams {
+    pub vm: String,
+}
+
+/// Parameters of [`VmProcedures::eject_cd`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmEjectCdParams {
+    pub id: String,
+}
+
+/// Parameters of [`VmProcedures::export`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmExportParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compress: Option<JsonValue>,
+    pub vm: String,
+}
+
+/// Parameters of [`VmProcedures::get_cloud_init_config`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmGetCloudInitConfigParams {
+    pub template: String,
+}
+
+/// Parameters of [`VmProcedures::import`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmImportParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Map<String, JsonValue>>,
+    pub sr: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+/// Parameters of [`VmProcedures::import_backup`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmImportBackupParams {
+    pub file: String,
+    pub remote: String,
+    pub sr: String,
+}
+
+/// Parameters of [`VmProcedures::import_delta_backup`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmImportDeltaBackupParams {
+    #[serde(rename = "filePath")]
+    pub file_path: String,
+    #[serde(rename = "mapVdisSrs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub map_vdis_srs: Option<serde_json::Map<String, JsonValue>>,
+    pub remote: String,
+    pub sr: String,
+}
+
+/// Parameters of [`VmProcedures::insert_cd`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmInsertCdParams {
+    pub cd_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    pub id: String,
+}
+
+/// Parameters of [`VmProcedures::migrate`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmMigrateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    #[serde(rename = "mapVdisSrs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub map_vdis_srs: Option<serde_json::Map<String, JsonValue>>,
+    #[serde(rename = "mapVifsNetworks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub map_vifs_networks: Option<serde_json::Map<String, JsonValue>>,
+    #[serde(rename = "migrationNetwork")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migration_network: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sr: Option<String>,
+    #[serde(rename = "targetHost")]
+    pub target_host: String,
+    pub vm: String,
+}
+
+/// Parameters of [`VmProcedures::pause`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmPauseParams {
+    pub id: String,
+}
+
+/// Parameters of [`VmProcedures::recovery_start`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmRecoveryStartParams {
+    pub id: String,
+}
+
+/// Parameters of [`VmProcedures::restart`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmRestartParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    pub id: String,
+}
+
+/// Parameters of [`VmProcedures::resume`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmResumeParams {
+    pub id: String,
+}
+
+/// Parameters of [`VmProcedures::revert`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmRevertParams {
+    pub snapshot: String,
+}
+
+/// Parameters of [`VmProcedures::rolling_backup`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmRollingBackupParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compress: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth: Option<f64>,
+    pub id: String,
+    #[serde(rename = "remoteId")]
+    pub remote_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention: Option<f64>,
+    pub tag: String,
+}
+
+/// Parameters of [`VmProcedures::rolling_delta_backup`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmRollingDeltaBackupParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth: Option<JsonValue>,
+    pub id: String,
+    pub remote: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention: Option<JsonValue>,
+    pub tag: String,
+}
+
+/// Parameters of [`VmProcedures::rolling_dr_copy`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmRollingDrCopyParams {
+    #[serde(rename = "deleteOldBackupsFirst")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_old_backups_first: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth: Option<f64>,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sr: Option<String>,
+    pub tag: String,
+}
+
+/// Parameters of [`VmProcedures::rolling_snapshot`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmRollingSnapshotParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth: Option<f64>,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention: Option<f64>,
+    pub tag: String,
+}
+
+/// Parameters of [`VmProcedures::set`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmSetParams {
+    #[serde(rename = "CPUs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<i64>,
+    #[serde(rename = "PV_args")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pv_args: Option<String>,
+    #[serde(rename = "affinityHost")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affinity_host: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_poweron: Option<bool>,
+    #[serde(rename = "blockedOperations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked_operations: Option<serde_json::Map<String, JsonValue>>,
+    #[serde(rename = "coresPerSocket")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cores_per_socket: Option<Option<JsonValue>>,
+    #[serde(rename = "cpuCap")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_cap: Option<Option<i64>>,
+    #[serde(rename = "cpuMask")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_mask: Option<Vec<JsonValue>>,
+    #[serde(rename = "cpuWeight")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_weight: Option<Option<i64>>,
+    #[serde(rename = "cpusMax")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpus_max: Option<JsonValue>,
+    #[serde(rename = "expNestedHvm")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_nested_hvm: Option<bool>,
+    #[serde(rename = "hasVendorDevice")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_vendor_device: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub high_availability: Option<String>,
+    #[serde(rename = "hvmBootFirmware")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hvm_boot_firmware: Option<Option<String>>,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<JsonValue>,
+    #[serde(rename = "memoryMax")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_max: Option<JsonValue>,
+    #[serde(rename = "memoryMin")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_min: Option<JsonValue>,
+    #[serde(rename = "memoryStaticMax")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_static_max: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_label: Option<String>,
+    #[serde(rename = "nicType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nic_type: Option<Option<String>>,
+    #[serde(rename = "resourceSet")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_set: Option<Option<String>>,
+    #[serde(rename = "secureBoot")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secure_boot: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share: Option<bool>,
+    #[serde(rename = "startDelay")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_delay: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vga: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub videoram: Option<f64>,
+    #[serde(rename = "virtualizationMode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub virtualization_mode: Option<String>,
+}
+
+/// Parameters of [`VmProcedures::set_boot_order`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmSetBootOrderParams {
+    pub order: String,
+    pub vm: String,
+}
+
+/// Parameters of [`VmProcedures::snapshot`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmSnapshotParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "saveMemory")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub save_memory: Option<bool>,
+}
+
+/// Parameters of [`VmProcedures::start`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmStartParams {
+    #[serde(rename = "bypassMacAddressesCheck")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bypass_mac_addresses_check: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    pub id: String,
+}
+
+/// Parameters of [`VmProcedures::stats`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmStatsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granularity: Option<String>,
+    pub id: String,
+}
+
+/// Parameters of [`VmProcedures::stop`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmStopParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    pub id: String,
+}
+
+/// Parameters of [`VmProcedures::suspend`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct VmSuspendParams {
+    pub id: String,
+}
+
+pub struct VmProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl VmProcedures {
+    /// xo-cli: vm.attachDisk [bootable=<boolean>] [mode=<string>] [position=<string>] vdi=<string> vm=<string>
+    pub async fn attach_disk<R: DeserializeOwned>(
+        &self,
+        params: VmAttachDiskParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.attachDisk", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.attachPci pciId=<string> vm=<string>
+    pub async fn attach_pci<R: DeserializeOwned>(
+        &self,
+        params: VmAttachPciParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.attachPci", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Exports a VM to the file system
+    ///
+    /// xo-cli: vm.backup [compress=<boolean>] file=<string> id=<string> remoteId=<string>
+    pub async fn backup<R: DeserializeOwned>(
+        &self,
+        params: VmBackupParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.backup", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.clone full_copy=<boolean> id=<string> name=<string>
+    pub async fn clone<R: DeserializeOwned>(
+        &self,
+        params: VmCloneParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.clone", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.convert id=<string>
+    pub async fn convert<R: DeserializeOwned>(
+        &self,
+        params: VmConvertParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.convert", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.convertToTemplate id=<string>
+    pub async fn convert_to_template<R: DeserializeOwned>(
+        &self,
+        params: VmConvertToTemplateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.convertToTemplate", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.copy [compress=<boolean|string>] [name=<string>] sr=<string> vm=<string>
+    pub async fn copy<R: DeserializeOwned>(
+        &self,
+        params: VmCopyParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.copy", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.copyToTemplate id=<string>
+    pub async fn copy_to_template<R: DeserializeOwned>(
+        &self,
+        params: VmCopyToTemplateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.copyToTemplate", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.create [VDIs=<array>] [VIFs=<array>] [affinityHost=<string>] [bootAfterCreate=<boolean>] [clone=<boolean>] [cloudConfig=<string>] [copyHostBiosStrings=<boolean>] [coreOs=<boolean>] [coresPerSocket=<string|number>] [existingDisks=<object>] [gpuGroup=<string>] [hvmBootFirmware=<string>] [installation=<object>] [name_description=<string>] name_label=<string> [networkConfig=<string>] [pv_args=<string>] [resourceSet=<string>] [share=<boolean>] template=<string> [vgpuType=<string>] *=<any>
+    pub async fn create<R: DeserializeOwned>(
+        &self,
+        params: VmCreateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.create", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.createCloudInitConfigDrive config=<string> [networkConfig=<string>] sr=<string> vm=<string>
+    pub async fn create_cloud_init_config_drive<R: DeserializeOwned>(
+        &self,
+        params: VmCreateCloudInitConfigDriveParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.createCloudInitConfigDrive", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.createInterface [allowedIpv4Addresses=<array>] [allowedIpv6Addresses=<array>] [mac=<string>] network=<string> [position=<integer|string>] vm=<string>
+    pub async fn create_interface<R: DeserializeOwned>(
+        &self,
+        params: VmCreateInterfaceParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.createInterface", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.createVgpu gpuGroup=<string> vgpuType=<string> vm=<string>
+    pub async fn create_vgpu<R: DeserializeOwned>(
+        &self,
+        params: VmCreateVgpuParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.createVgpu", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.delete [deleteDisks=<boolean>] [force=<boolean>] [forceDeleteDefaultTemplate=<boolean>] id=<string>
+    pub async fn delete<R: DeserializeOwned>(
+        &self,
+        params: VmDeleteParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.delete", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.deleteVgpu vgpu=<string>
+    pub async fn delete_vgpu<R: DeserializeOwned>(
+        &self,
+        params: VmDeleteVgpuParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.deleteVgpu", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.deltaCopy [force=<boolean>] id=<string> [retention=<number>] sr=<string>
+    pub async fn delta_copy<R: DeserializeOwned>(
+        &self,
+        params: VmDeltaCopyParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.deltaCopy", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.detachPci vm=<string>
+    pub async fn detach_pci<R: DeserializeOwned>(
+        &self,
+        params: VmDetachPciParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.detachPci", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.ejectCd id=<string>
+    pub async fn eject_cd<R: DeserializeOwned>(
+        &self,
+        params: VmEjectCdParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.ejectCd", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.export [compress=<boolean|string>] vm=<string>
+    pub async fn export<R: DeserializeOwned>(
+        &self,
+        params: VmExportParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.export", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.getCloudInitConfig template=<string>
+    pub async fn get_cloud_init_config<R: DeserializeOwned>(
+        &self,
+        params: VmGetCloudInitConfigParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.getCloudInitConfig", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.getHaValues
+    pub async fn get_ha_values<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("vm.getHaValues", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// xo-cli: vm.import [data=<object>] sr=<string> [type=<string>]
+    pub async fn import<R: DeserializeOwned>(
+        &self,
+        params: VmImportParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.import", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Imports a VM into host, from a file found in the chosen remote
+    ///
+    /// xo-cli: vm.importBackup file=<string> remote=<string> sr=<string>
+    pub async fn import_backup<R: DeserializeOwned>(
+        &self,
+        params: VmImportBackupParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.importBackup", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.importDeltaBackup filePath=<string> [mapVdisSrs=<object>] remote=<string> sr=<string>
+    pub async fn import_delta_backup<R: DeserializeOwned>(
+        &self,
+        params: VmImportDeltaBackupParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.importDeltaBackup", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.insertCd cd_id=<string> [force=<boolean>] id=<string>
+    pub async fn insert_cd<R: DeserializeOwned>(
+        &self,
+        params: VmInsertCdParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.insertCd", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.migrate [force=<boolean>] [mapVdisSrs=<object>] [mapVifsNetworks=<object>] [migrationNetwork=<string>] [sr=<string>] targetHost=<string> vm=<string>
+    pub async fn migrate<R: DeserializeOwned>(
+        &self,
+        params: VmMigrateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.migrate", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.pause id=<string>
+    pub async fn pause<R: DeserializeOwned>(
+        &self,
+        params: VmPauseParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.pause", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.recoveryStart id=<string>
+    pub async fn recovery_start<R: DeserializeOwned>(
+        &self,
+        params: VmRecoveryStartParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.recoveryStart", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.restart [force=<boolean>] id=<string>
+    pub async fn restart<R: DeserializeOwned>(
+        &self,
+        params: VmRestartParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.restart", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.resume id=<string>
+    pub async fn resume<R: DeserializeOwned>(
+        &self,
+        params: VmResumeParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.resume", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.revert snapshot=<string>
+    pub async fn revert<R: DeserializeOwned>(
+        &self,
+        params: VmRevertParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.revert", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Exports a VM to the file system with a tagged name, and removes the oldest backup with the same tag according to retention
+    ///
+    /// xo-cli: vm.rollingBackup [compress=<boolean>] [depth=<number>] id=<string> remoteId=<string> [retention=<number>] tag=<string>
+    pub async fn rolling_backup<R: DeserializeOwned>(
+        &self,
+        params: VmRollingBackupParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.rollingBackup", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.rollingDeltaBackup [depth=<string|number>] id=<string> remote=<string> [retention=<string|number>] tag=<string>
+    pub async fn rolling_delta_backup<R: DeserializeOwned>(
+        &self,
+        params: VmRollingDeltaBackupParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.rollingDeltaBackup", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Copies a VM to a different pool, with a tagged name, and removes the oldest VM with the same tag from this pool, according to retention
+    ///
+    /// xo-cli: vm.rollingDrCopy [deleteOldBackupsFirst=<boolean>] [depth=<number>] id=<string> [pool=<string>] [retention=<number>] [sr=<string>] tag=<string>
+    pub async fn rolling_dr_copy<R: DeserializeOwned>(
+        &self,
+        params: VmRollingDrCopyParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.rollingDrCopy", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Snapshots a VM with a tagged name, and removes the oldest snapshot with the same tag according to retention
+    ///
+    /// xo-cli: vm.rollingSnapshot [depth=<number>] id=<string> [retention=<number>] tag=<string>
+    pub async fn rolling_snapshot<R: DeserializeOwned>(
+        &self,
+        params: VmRollingSnapshotParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.rollingSnapshot", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.set [CPUs=<integer>] [PV_args=<string>] [affinityHost=<string|null>] [auto_poweron=<boolean>] [blockedOperations=<object>] [coresPerSocket=<string|number|null>] [cpuCap=<integer|null>] [cpuMask=<array>] [cpuWeight=<integer|null>] [cpusMax=<integer|string>] [expNestedHvm=<boolean>] [hasVendorDevice=<boolean>] [high_availability=<string>] [hvmBootFirmware=<string|null>] id=<string> [memory=<integer|string>] [memoryMax=<integer|string>] [memoryMin=<integer|string>] [memoryStaticMax=<integer|string>] [name_description=<string>] [name_label=<string>] [nicType=<string|null>] [resourceSet=<string|null>] [secureBoot=<boolean>] [share=<boolean>] [startDelay=<integer>] [vga=<string>] [videoram=<number>] [virtualizationMode=<string>]
+    pub async fn set<R: DeserializeOwned>(
+        &self,
+        params: VmSetParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.set", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.setBootOrder order=<string> vm=<string>
+    pub async fn set_boot_order<R: DeserializeOwned>(
+        &self,
+        params: VmSetBootOrderParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.setBootOrder", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.snapshot [description=<string>] id=<string> [name=<string>] [saveMemory=<boolean>]
+    pub async fn snapshot<R: DeserializeOwned>(
+        &self,
+        params: VmSnapshotParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.snapshot", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.start [bypassMacAddressesCheck=<boolean>] [force=<boolean>] [host=<string>] id=<string>
+    pub async fn start<R: DeserializeOwned>(
+        &self,
+        params: VmStartParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.start", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Returns statistics about the VM
+    ///
+    /// xo-cli: vm.stats [granularity=<string>] id=<string>
+    pub async fn stats<R: DeserializeOwned>(
+        &self,
+        params: VmStatsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.stats", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.stop [force=<boolean>] id=<string>
+    pub async fn stop<R: DeserializeOwned>(
+        &self,
+        params: VmStopParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.stop", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: vm.suspend id=<string>
+    pub async fn suspend<R: DeserializeOwned>(
+        &self,
+        params: VmSuspendParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("vm.suspend", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
@@ -0,0 +1,85 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`XoProcedures::export_config`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct XoExportConfigParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entries: Option<Vec<JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passphrase: Option<String>,
+}
+
+/// Parameters of [`XoProcedures::get_all_objects`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct XoGetAllObjectsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<serde_json::Map<String, JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ndjson: Option<bool>,
+}
+
+/// Parameters of [`XoProcedures::import_config`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct XoImportConfigParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passphrase: Option<String>,
+}
+
+pub struct XoProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl XoProcedures {
+    /// xo-cli: xo.clean
+    pub async fn clean<R: DeserializeOwned>(&self) -> Result<R, RpcError> {
+        self.inner
+            .request("xo.clean", Some(ParamsSer::Map(procedure_args! {})))
+            .await
+    }
+
+    /// xo-cli: xo.exportConfig [entries=<array>] [passphrase=<string>]
+    pub async fn export_config<R: DeserializeOwned>(
+        &self,
+        params: XoExportConfigParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("xo.exportConfig", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Returns all XO objects
+    ///
+    /// xo-cli: xo.getAllObjects [filter=<object>] [limit=<number>] [ndjson=<boolean>]
+    pub async fn get_all_objects<R: DeserializeOwned>(
+        &self,
+        params: XoGetAllObjectsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("xo.getAllObjects", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: xo.importConfig [passphrase=<string>]
+    pub async fn import_config<R: DeserializeOwned>(
+        &self,
+        params: XoImportConfigParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("xo.importConfig", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
@@ -0,0 +1,272 @@
+// Generated by xo-api-codegen from system.getMethodsInfo, do not edit
+
+use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned, JsonValue};
+use jsonrpsee_ws_client::WsClient;
+use std::sync::Arc;
+
+use crate::RpcError;
+
+/// Parameters of [`XosanProcedures::add_bricks`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct XosanAddBricksParams {
+    #[serde(rename = "brickSize")]
+    pub brick_size: f64,
+    pub lvmsrs: Vec<JsonValue>,
+    pub xosansr: String,
+}
+
+/// Parameters of [`XosanProcedures::check_sr_current_state`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct XosanCheckSrCurrentStateParams {
+    #[serde(rename = "poolId")]
+    pub pool_id: String,
+}
+
+/// Parameters of [`XosanProcedures::compute_xosan_possible_options`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct XosanComputeXosanPossibleOptionsParams {
+    #[serde(rename = "brickSize")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brick_size: Option<f64>,
+    #[serde(rename = "lvmSrs")]
+    pub lvm_srs: Vec<JsonValue>,
+}
+
+/// Parameters of [`XosanProcedures::create_sr`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct XosanCreateSrParams {
+    #[serde(rename = "brickSize")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brick_size: Option<f64>,
+    #[serde(rename = "glusterType")]
+    pub gluster_type: String,
+    #[serde(rename = "ipRange")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_range: Option<String>,
+    #[serde(rename = "memorySize")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_size: Option<f64>,
+    pub pif: String,
+    pub redundancy: f64,
+    pub srs: Vec<JsonValue>,
+    pub template: serde_json::Map<String, JsonValue>,
+    pub vlan: String,
+}
+
+/// Parameters of [`XosanProcedures::download_and_install_xosan_pack`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct XosanDownloadAndInstallXosanPackParams {
+    pub id: String,
+    pub pool: String,
+    pub version: String,
+}
+
+/// Parameters of [`XosanProcedures::fix_host_not_in_network`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct XosanFixHostNotInNetworkParams {
+    pub host: String,
+    #[serde(rename = "xosanSr")]
+    pub xosan_sr: String,
+}
+
+/// Parameters of [`XosanProcedures::get_volume_info`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct XosanGetVolumeInfoParams {
+    #[serde(rename = "infoType")]
+    pub info_type: String,
+    pub sr: String,
+}
+
+/// Parameters of [`XosanProcedures::profile_status`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct XosanProfileStatusParams {
+    #[serde(rename = "changeStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_status: Option<bool>,
+    pub sr: String,
+}
+
+/// Parameters of [`XosanProcedures::remove_bricks`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct XosanRemoveBricksParams {
+    pub bricks: Vec<JsonValue>,
+    pub xosansr: String,
+}
+
+/// Parameters of [`XosanProcedures::replace_brick`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct XosanReplaceBrickParams {
+    #[serde(rename = "brickSize")]
+    pub brick_size: f64,
+    #[serde(rename = "newLvmSr")]
+    pub new_lvm_sr: String,
+    #[serde(rename = "previousBrick")]
+    pub previous_brick: String,
+    pub xosansr: String,
+}
+
+/// Parameters of [`XosanProcedures::unlock`]
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct XosanUnlockParams {
+    #[serde(rename = "licenseId")]
+    pub license_id: String,
+    pub sr: String,
+}
+
+pub struct XosanProcedures {
+    pub(crate) inner: Arc<WsClient>,
+}
+
+impl XosanProcedures {
+    /// Add brick to XOSAN SR
+    ///
+    /// xo-cli: xosan.addBricks brickSize=<number> lvmsrs=<array> xosansr=<string>
+    pub async fn add_bricks<R: DeserializeOwned>(
+        &self,
+        params: XosanAddBricksParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("xosan.addBricks", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Checks if there is an operation currently running on the SR
+    ///
+    /// xo-cli: xosan.checkSrCurrentState poolId=<string>
+    pub async fn check_sr_current_state<R: DeserializeOwned>(
+        &self,
+        params: XosanCheckSrCurrentStateParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("xosan.checkSrCurrentState", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// xo-cli: xosan.computeXosanPossibleOptions [brickSize=<number>] lvmSrs=<array>
+    pub async fn compute_xosan_possible_options<R: DeserializeOwned>(
+        &self,
+        params: XosanComputeXosanPossibleOptionsParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("xosan.computeXosanPossibleOptions", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Create gluster VM
+    ///
+    /// xo-cli: xosan.createSR [brickSize=<number>] glusterType=<string> [ipRange=<string>] [memorySize=<number>] pif=<string> redundancy=<number> srs=<array> template=<object> vlan=<string>
+    pub async fn create_sr<R: DeserializeOwned>(
+        &self,
+        params: XosanCreateSrParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("xosan.createSR", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Register a resource via cloud plugin
+    ///
+    /// xo-cli: xosan.downloadAndInstallXosanPack id=<string> pool=<string> version=<string>
+    pub async fn download_and_install_xosan_pack<R: DeserializeOwned>(
+        &self,
+        params: XosanDownloadAndInstallXosanPackParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("xosan.downloadAndInstallXosanPack", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Put host in xosan network
+    ///
+    /// xo-cli: xosan.fixHostNotInNetwork host=<string> xosanSr=<string>
+    pub async fn fix_host_not_in_network<R: DeserializeOwned>(
+        &self,
+        params: XosanFixHostNotInNetworkParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("xosan.fixHostNotInNetwork", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Info on gluster volume
+    ///
+    /// xo-cli: xosan.getVolumeInfo infoType=<string> sr=<string>
+    pub async fn get_volume_info<R: DeserializeOwned>(
+        &self,
+        params: XosanGetVolumeInfoParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("xosan.getVolumeInfo", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Activate, deactivate, or interrogate profile data
+    ///
+    /// xo-cli: xosan.profileStatus [changeStatus=<boolean>] sr=<string>
+    pub async fn profile_status<R: DeserializeOwned>(
+        &self,
+        params: XosanProfileStatusParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("xosan.profileStatus", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Remove brick from XOSAN SR
+    ///
+    /// xo-cli: xosan.removeBricks bricks=<array> xosansr=<string>
+    pub async fn remove_bricks<R: DeserializeOwned>(
+        &self,
+        params: XosanRemoveBricksParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("xosan.removeBricks", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// ReplaceBrick brick in gluster volume
+    ///
+    /// xo-cli: xosan.replaceBrick brickSize=<number> newLvmSr=<string> previousBrick=<string> xosansr=<string>
+    pub async fn replace_brick<R: DeserializeOwned>(
+        &self,
+        params: XosanReplaceBrickParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("xosan.replaceBrick", Some(ParamsSer::Map(params)))
+            .await
+    }
+
+    /// Unlock XOSAN SR functionalities by binding it to a paid license
+    ///
+    /// xo-cli: xosan.unlock licenseId=<string> sr=<string>
+    pub async fn unlock<R: DeserializeOwned>(
+        &self,
+        params: XosanUnlockParams,
+    ) -> Result<R, RpcError> {
+        struct_to_map!(let params = params);
+
+        self.inner
+            .request("xosan.unlock", Some(ParamsSer::Map(params)))
+            .await
+    }
+}
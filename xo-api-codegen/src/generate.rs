@@ -0,0 +1,295 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use xo_api_client::api::system::{MethodInfo, ParamSchema};
+
+/// Generate one file per namespace, plus `mod.rs`, indexed by file name
+pub fn generate(methods: &BTreeMap<String, MethodInfo>) -> BTreeMap<String, String> {
+    let mut namespaces: BTreeMap<&str, Vec<(&str, &MethodInfo)>> = BTreeMap::new();
+    for (name, info) in methods {
+        let (namespace, method) = name.split_once('.').unwrap_or(("xo", name));
+        namespaces
+            .entry(namespace)
+            .or_default()
+            .push((method, info));
+    }
+
+    let mut files = BTreeMap::new();
+    let mut mod_rs = String::from(HEADER);
+    for (namespace, methods) in &namespaces {
+        let module = snake_case(namespace);
+        writeln!(mod_rs, "pub mod {};", module).unwrap();
+        files.insert(format!("{}.rs", module), namespace_file(namespace, methods));
+    }
+    files.insert("mod.rs".to_string(), mod_rs);
+
+    files
+}
+
+const HEADER: &str = "// Generated by xo-api-codegen from system.getMethodsInfo, do not edit\n\n";
+
+fn namespace_file(namespace: &str, methods: &[(&str, &MethodInfo)]) -> String {
+    let procedures = format!("{}Procedures", pascal_case(namespace));
+
+    // Some methods only differ in case, like the deprecated `host.restart_agent` and
+    // `host.restartAgent`, or a trailing underscore like `vdi.delete_`. Number them to
+    // keep the names unique
+    let mut names: Vec<String> = Vec::new();
+    for (method, _) in methods {
+        let name = snake_case(method);
+        let mut unique = name.clone();
+        let mut i = 2;
+        while names.contains(&unique) {
+            unique = format!("{}{}", name, i);
+            i += 1;
+        }
+        names.push(unique);
+    }
+
+    let mut structs = String::new();
+    for ((_, info), name) in methods.iter().zip(&names) {
+        if !info.params.is_empty() {
+            let link = format!("{}::{}", procedures, ident(name));
+            structs.push('\n');
+            params_struct(&mut structs, &params_name(namespace, name), &link, info);
+        }
+    }
+
+    // Only import what is used, to not cause warnings. The macros of xo-api-client are
+    // in scope through `#[macro_use]`, importing them too would be reported as unused
+    let json_value = structs.contains("JsonValue");
+
+    let mut out = String::from(HEADER);
+    out.push_str(
+        "use jsonrpsee_types::{traits::Client as _, v2::params::ParamsSer, DeserializeOwned",
+    );
+    out.push_str(if json_value {
+        ", JsonValue};\n"
+    } else {
+        "};\n"
+    });
+    out.push_str("use jsonrpsee_ws_client::WsClient;\n");
+    if structs.contains("BTreeMap") {
+        out.push_str("use std::{collections::BTreeMap, sync::Arc};\n");
+    } else {
+        out.push_str("use std::sync::Arc;\n");
+    }
+    out.push_str("\nuse crate::RpcError;\n");
+    out.push_str(&structs);
+
+    writeln!(out, "\npub struct {} {{", procedures).unwrap();
+    out.push_str("    pub(crate) inner: Arc<WsClient>,\n}\n");
+    writeln!(out, "\nimpl {} {{", procedures).unwrap();
+    for (i, ((method, info), name)) in methods.iter().zip(&names).enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        procedure(&mut out, namespace, method, name, info);
+    }
+    out.push_str("}\n");
+
+    out
+}
+
+fn params_struct(out: &mut String, name: &str, procedure: &str, info: &MethodInfo) {
+    writeln!(out, "/// Parameters of [`{}`]", procedure).unwrap();
+    out.push_str("#[derive(serde::Serialize, Debug, Clone)]\n");
+    writeln!(out, "pub struct {} {{", name).unwrap();
+
+    let mut used = Vec::new();
+    for (i, (param, schema)) in info.params.iter().enumerate() {
+        let documented = schema.description.is_some() || schema.allowed_values.is_some();
+        if i > 0 && documented {
+            out.push('\n');
+        }
+        if let Some(description) = &schema.description {
+            writeln!(out, "    /// {}", description).unwrap();
+        }
+        if let Some(values) = &schema.allowed_values {
+            let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+            writeln!(out, "    /// One of {}", values.join(", ")).unwrap();
+        }
+
+        // `*` means any other parameters are accepted
+        if param == "*" {
+            out.push_str("    #[serde(flatten)]\n");
+            out.push_str("    pub extra: BTreeMap<String, JsonValue>,\n");
+            continue;
+        }
+
+        let mut field = snake_case(param);
+        while used.contains(&field) {
+            field.push('_');
+        }
+        used.push(field.clone());
+
+        if field != *param {
+            writeln!(out, "    #[serde(rename = \"{}\")]", param).unwrap();
+        }
+        let ty = nullable_type(schema);
+        if schema.optional {
+            out.push_str("    #[serde(skip_serializing_if = \"Option::is_none\")]\n");
+            writeln!(out, "    pub {}: Option<{}>,", ident(&field), ty).unwrap();
+        } else {
+            writeln!(out, "    pub {}: {},", ident(&field), ty).unwrap();
+        }
+    }
+
+    out.push_str("}\n");
+}
+
+fn procedure(out: &mut String, namespace: &str, method: &str, name: &str, info: &MethodInfo) {
+    let full_name = format!("{}.{}", namespace, method);
+
+    if let Some(description) = &info.description {
+        writeln!(out, "    /// {}", capitalize(description)).unwrap();
+        out.push_str("    ///\n");
+    }
+    if let Some(permission) = &info.permission {
+        writeln!(out, "    /// Requires permission: {}", permission).unwrap();
+        out.push_str("    ///\n");
+    }
+    // Like xo-cli, `*` goes last
+    let mut signature = full_name.clone();
+    let (rest, named): (Vec<_>, Vec<_>) = info.params.iter().partition(|(param, _)| *param == "*");
+    for (param, schema) in named.into_iter().chain(rest) {
+        signature.push(' ');
+        signature.push_str(&schema.signature(param));
+    }
+    writeln!(out, "    /// xo-cli: {}", signature).unwrap();
+
+    let params = params_name(namespace, name);
+    let name = ident(name);
+    if info.params.is_empty() {
+        writeln!(
+            out,
+            "    pub async fn {}<R: DeserializeOwned>(&self) -> Result<R, RpcError> {{",
+            name
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "        self.inner\n            .request(\"{}\", Some(ParamsSer::Map(procedure_args! {{}})))\n            .await",
+            full_name
+        )
+        .unwrap();
+    } else {
+        writeln!(
+            out,
+            "    pub async fn {}<R: DeserializeOwned>(\n        &self,\n        params: {},\n    ) -> Result<R, RpcError> {{",
+            name, params
+        )
+        .unwrap();
+        out.push_str("        struct_to_map!(let params = params);\n\n");
+        writeln!(
+            out,
+            "        self.inner\n            .request(\"{}\", Some(ParamsSer::Map(params)))\n            .await",
+            full_name
+        )
+        .unwrap();
+    }
+    out.push_str("    }\n");
+}
+
+/// Rust type of a parameter, `null` being allowed makes it an `Option`
+fn nullable_type(schema: &ParamSchema) -> String {
+    let types: Vec<&str> = schema
+        .types
+        .iter()
+        .map(String::as_str)
+        .filter(|ty| *ty != "null")
+        .collect();
+    let ty = match &types[..] {
+        [ty] => rust_type(ty, schema),
+        _ => "JsonValue".to_string(),
+    };
+
+    if types.len() < schema.types.len() {
+        format!("Option<{}>", ty)
+    } else {
+        ty
+    }
+}
+
+fn rust_type(ty: &str, schema: &ParamSchema) -> String {
+    match ty {
+        "string" => "String".to_string(),
+        "boolean" => "bool".to_string(),
+        "number" => "f64".to_string(),
+        "integer" => "i64".to_string(),
+        "array" => match &schema.items {
+            Some(items) => format!("Vec<{}>", nullable_type(items)),
+            None => "Vec<JsonValue>".to_string(),
+        },
+        "object" => "serde_json::Map<String, JsonValue>".to_string(),
+        _ => "JsonValue".to_string(),
+    }
+}
+
+fn params_name(namespace: &str, name: &str) -> String {
+    format!("{}{}Params", pascal_case(namespace), pascal_case(name))
+}
+
+/// `getMethodsInfo` -> `get_methods_info`, `VIFs` -> `vifs`, `usageReport.send` -> `usage_report_send`
+pub fn snake_case(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_ascii_alphanumeric() {
+            if !out.is_empty() && !out.ends_with('_') {
+                out.push('_');
+            }
+            continue;
+        }
+
+        if c.is_ascii_uppercase() && i > 0 && !out.ends_with('_') {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(char::is_ascii_lowercase);
+            // Keep runs of capitals together, unless the last one starts a new word
+            // like the `V` in `VDIsVm`
+            if prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && next_is_lower && chars[i + 1] != 's')
+            {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+
+    out.trim_end_matches('_').to_string()
+}
+
+/// `backupNg` -> `BackupNg`, `usageReport.send` -> `UsageReportSend`
+pub fn pascal_case(s: &str) -> String {
+    snake_case(s)
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .map(capitalize)
+        .collect()
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Escape identifiers which are keywords
+fn ident(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as", "async", "await", "box", "break", "const", "continue", "dyn", "else", "enum",
+        "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+        "mut", "pub", "ref", "return", "static", "struct", "trait", "true", "try", "type",
+        "unsafe", "use", "where", "while", "yield",
+    ];
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", name)
+    } else if KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.to_string()
+    }
+}
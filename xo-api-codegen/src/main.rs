@@ -0,0 +1,58 @@
+//! Generate procedure bindings from a saved `system.getMethodsInfo` dump
+//!
+//! Usage: `xo-api-codegen <methods_info.json> <output directory>`
+//!
+//! One file is written per namespace, like `vm.rs`, containing a parameter struct and a
+//! method on `*Procedures` for every API method of the namespace, along with a `mod.rs`
+//! declaring them. The output is meant as a starting point for hand-written bindings in
+//! `src/api/`, not to be used as is.
+//!
+//! A dump can be saved with `xo-cli system.getMethodsInfo --json > methods_info.json`.
+//! The checked in `snapshot/methods_info.json` was built from `all_procedures.md`, so it
+//! lacks parameter descriptions and permissions which a live dump has.
+//! The snapshot in `snapshot/` is regenerated with
+//! `cargo run -p xo-api-codegen -- snapshot/methods_info.json snapshot/generated` from
+//! within this directory.
+
+#[cfg(test)]
+mod tests;
+
+mod generate;
+
+use std::{collections::BTreeMap, env, fs, path::Path, process};
+
+use xo_api_client::api::system::MethodInfo;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let (input, output) = match &args[..] {
+        [_, input, output] => (Path::new(input), Path::new(output)),
+        _ => {
+            eprintln!("Usage: xo-api-codegen <methods_info.json> <output directory>");
+            process::exit(2);
+        }
+    };
+
+    let content = fs::read_to_string(input).unwrap_or_else(|e| {
+        eprintln!("Failed to read {}: {}", input.display(), e);
+        process::exit(1);
+    });
+    let methods: BTreeMap<String, MethodInfo> =
+        serde_json::from_str(&content).unwrap_or_else(|e| {
+            eprintln!("Invalid methods info in {}: {}", input.display(), e);
+            process::exit(1);
+        });
+
+    let files = generate::generate(&methods);
+
+    fs::create_dir_all(output).expect("Failed to create output directory");
+    for (name, content) in &files {
+        fs::write(output.join(name), content).expect("Failed to write output");
+    }
+
+    eprintln!(
+        "Generated {} methods in {} namespaces",
+        methods.len(),
+        files.len() - 1
+    );
+}
@@ -0,0 +1,45 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use xo_api_client::api::system::MethodInfo;
+
+use crate::generate::{generate, pascal_case, snake_case};
+
+#[test]
+fn names() {
+    assert_eq!(snake_case("getMethodsInfo"), "get_methods_info");
+    assert_eq!(snake_case("name_label"), "name_label");
+    assert_eq!(snake_case("VIFs"), "vifs");
+    assert_eq!(snake_case("VDIsVm"), "vdis_vm");
+    assert_eq!(snake_case("httpProxy"), "http_proxy");
+    assert_eq!(snake_case("HTTPProxy"), "http_proxy");
+    assert_eq!(snake_case("usageReport.send"), "usage_report_send");
+    assert_eq!(pascal_case("backupNg"), "BackupNg");
+    assert_eq!(pascal_case("usageReport.send"), "UsageReportSend");
+}
+
+/// The checked in snapshot must match what the generator produces for the checked in
+/// dump, see the module documentation of `main.rs` for how to regenerate it
+#[test]
+fn snapshot() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshot");
+    let content = fs::read_to_string(dir.join("methods_info.json")).unwrap();
+    let methods: BTreeMap<String, MethodInfo> = serde_json::from_str(&content).unwrap();
+
+    let files = generate(&methods);
+
+    let mut existing: Vec<String> = fs::read_dir(dir.join("generated"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    existing.sort();
+    assert_eq!(existing, files.keys().cloned().collect::<Vec<_>>());
+
+    for (name, content) in &files {
+        let snapshot = fs::read_to_string(dir.join("generated").join(name)).unwrap();
+        assert!(
+            snapshot == *content,
+            "snapshot/generated/{} is out of date",
+            name
+        );
+    }
+}